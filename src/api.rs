@@ -0,0 +1,99 @@
+//! Object-safe trait over the client's API calls
+
+use crate::{client::Client, error::Result, types::*};
+use async_trait::async_trait;
+
+/// The ShopSavvy Data API calls, implemented by [`Client`]
+///
+/// Depend on `Arc<dyn ShopSavvyApi>` instead of `Client` to swap in a mock in
+/// tests. Methods mirror the client's; identifiers are plain `&str` so the
+/// trait stays object safe.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct PriceService {
+///     api: Arc<dyn ShopSavvyApi>,
+/// }
+///
+/// let service = PriceService { api: Arc::new(Client::new(api_key)?) };
+/// ```
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+pub trait ShopSavvyApi {
+    async fn search_products(&self, query: &str, limit: Option<i32>, offset: Option<i32>) -> Result<ProductSearchResult>;
+
+    async fn get_product_details(&self, identifier: &str, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductDetails>>>;
+
+    async fn get_product_details_batch(&self, identifiers: &[&str], format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductDetails>>>;
+
+    async fn get_current_offers(&self, identifier: &str, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductWithOffers>>>;
+
+    async fn get_current_offers_batch(&self, identifiers: &[&str], retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>>;
+
+    async fn get_price_history(&self, identifier: &str, start_date: &str, end_date: &str, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<OfferWithHistory>>>;
+
+    async fn schedule_product_monitoring(&self, identifier: &str, frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleResponse>>;
+
+    async fn schedule_product_monitoring_batch(&self, identifiers: &[&str], frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleBatchResults>>;
+
+    async fn get_scheduled_products(&self) -> Result<ApiResponse<Vec<ScheduledProduct>>>;
+
+    async fn remove_product_from_schedule(&self, identifier: &str) -> Result<ApiResponse<RemoveResponse>>;
+
+    async fn remove_products_from_schedule(&self, identifiers: &[&str]) -> Result<ApiResponse<RemoveBatchResults>>;
+
+    async fn get_usage(&self) -> Result<ApiResponse<UsageInfo>>;
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl ShopSavvyApi for Client {
+    async fn search_products(&self, query: &str, limit: Option<i32>, offset: Option<i32>) -> Result<ProductSearchResult> {
+        Client::search_products(self, query, limit, offset).await
+    }
+
+    async fn get_product_details(&self, identifier: &str, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductDetails>>> {
+        Client::get_product_details(self, identifier, format, country).await
+    }
+
+    async fn get_product_details_batch(&self, identifiers: &[&str], format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductDetails>>> {
+        Client::get_product_details_batch(self, identifiers, format).await
+    }
+
+    async fn get_current_offers(&self, identifier: &str, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        Client::get_current_offers(self, identifier, retailer, format, country).await
+    }
+
+    async fn get_current_offers_batch(&self, identifiers: &[&str], retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        Client::get_current_offers_batch(self, identifiers, retailer, format).await
+    }
+
+    async fn get_price_history(&self, identifier: &str, start_date: &str, end_date: &str, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<OfferWithHistory>>> {
+        Client::get_price_history(self, identifier, start_date, end_date, retailer, format, country).await
+    }
+
+    async fn schedule_product_monitoring(&self, identifier: &str, frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleResponse>> {
+        Client::schedule_product_monitoring(self, identifier, frequency, retailer).await
+    }
+
+    async fn schedule_product_monitoring_batch(&self, identifiers: &[&str], frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleBatchResults>> {
+        Client::schedule_product_monitoring_batch(self, identifiers, frequency, retailer).await
+    }
+
+    async fn get_scheduled_products(&self) -> Result<ApiResponse<Vec<ScheduledProduct>>> {
+        Client::get_scheduled_products(self).await
+    }
+
+    async fn remove_product_from_schedule(&self, identifier: &str) -> Result<ApiResponse<RemoveResponse>> {
+        Client::remove_product_from_schedule(self, identifier).await
+    }
+
+    async fn remove_products_from_schedule(&self, identifiers: &[&str]) -> Result<ApiResponse<RemoveBatchResults>> {
+        Client::remove_products_from_schedule(self, identifiers).await
+    }
+
+    async fn get_usage(&self) -> Result<ApiResponse<UsageInfo>> {
+        Client::get_usage(self).await
+    }
+}
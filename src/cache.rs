@@ -0,0 +1,82 @@
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use crate::time::Instant;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Cache settings for product details lookups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheConfig {
+    pub capacity: usize,
+    #[serde(with = "crate::time::millis")]
+    pub ttl: Duration,
+}
+
+/// In-memory LRU cache of raw response bodies with a time-to-live
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    entries: Mutex<LruCache<String, (Instant, String)>>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: &CacheConfig) -> Self {
+        let capacity = NonZeroUsize::new(config.capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl: config.ttl,
+        }
+    }
+
+    /// Get a cached body, evicting it if it has expired
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        let expired = match entries.get(key) {
+            Some((stored_at, body)) if stored_at.elapsed() < self.ttl => return Some(body.clone()),
+            Some(_) => true,
+            None => false,
+        };
+
+        if expired {
+            entries.pop(key);
+        }
+        None
+    }
+
+    pub(crate) fn insert(&self, key: String, body: String) {
+        self.entries.lock().unwrap().put(key, (Instant::now(), body));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// ETags and response bodies of previous GET requests, for conditional requests
+#[derive(Debug)]
+pub(crate) struct EtagCache {
+    entries: Mutex<LruCache<String, (String, String)>>,
+}
+
+impl EtagCache {
+    pub(crate) fn new(config: &CacheConfig) -> Self {
+        let capacity = NonZeroUsize::new(config.capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Get the stored `(etag, body)` pair for a request
+    pub(crate) fn get(&self, key: &str) -> Option<(String, String)> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    pub(crate) fn insert(&self, key: String, etag: String, body: String) {
+        self.entries.lock().unwrap().put(key, (etag, body));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,208 @@
+use crate::time::{Clock, Instant};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Circuit breaker settings for sustained API failures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// Failures further apart than this start a new count
+    #[serde(with = "crate::time::millis")]
+    pub window: Duration,
+    /// How long the circuit stays open before a probe request is allowed
+    #[serde(with = "crate::time::millis")]
+    pub cooldown: Duration,
+}
+
+/// Stops sending requests after repeated failures, shared by all client clones
+///
+/// Connection failures and 5xx responses count as failures. Once
+/// `failure_threshold` of them happen within `window`, the circuit opens and
+/// requests fail fast for `cooldown`. After that a single probe request is let
+/// through (half-open): success closes the circuit, failure reopens it.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    state: Mutex<State>,
+    config: CircuitBreakerConfig,
+    clock: Clock,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32, first_failure_at: Option<Instant> },
+    Open { until: Instant },
+    HalfOpen { since: Instant },
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: &CircuitBreakerConfig) -> Self {
+        Self::with_clock(config, Clock::System)
+    }
+
+    pub(crate) fn with_clock(config: &CircuitBreakerConfig, clock: Clock) -> Self {
+        Self {
+            state: Mutex::new(State::Closed {
+                failures: 0,
+                first_failure_at: None,
+            }),
+            config: config.clone(),
+            clock,
+        }
+    }
+
+    /// Check whether a request may be sent now
+    pub(crate) fn allow(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let now = self.clock.now();
+        match *state {
+            State::Closed { .. } => true,
+            State::Open { until } if now >= until => {
+                *state = State::HalfOpen { since: now };
+                true
+            }
+            State::Open { .. } => false,
+            // Allow a new probe if the previous one was abandoned
+            State::HalfOpen { since } if now.duration_since(since) >= self.config.cooldown => {
+                *state = State::HalfOpen { since: now };
+                true
+            }
+            State::HalfOpen { .. } => false,
+        }
+    }
+
+    pub(crate) fn record_success(&self) {
+        *self.state.lock().unwrap() = State::Closed {
+            failures: 0,
+            first_failure_at: None,
+        };
+    }
+
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        let now = self.clock.now();
+        let (failures, first_failure_at) = match *state {
+            State::Closed {
+                failures,
+                first_failure_at: Some(first),
+            } if now.duration_since(first) <= self.config.window => (failures + 1, first),
+            State::Closed { .. } => (1, now),
+            State::Open { .. } => return,
+            // The probe failed, so reopen straight away
+            State::HalfOpen { .. } => (self.config.failure_threshold, now),
+        };
+
+        *state = if failures >= self.config.failure_threshold {
+            State::Open {
+                until: now + self.config.cooldown,
+            }
+        } else {
+            State::Closed {
+                failures,
+                first_failure_at: Some(first_failure_at),
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(window: Duration, cooldown: Duration) -> (CircuitBreaker, Clock) {
+        let clock = Clock::manual();
+        let config = CircuitBreakerConfig {
+            failure_threshold: 2,
+            window,
+            cooldown,
+        };
+        (CircuitBreaker::with_clock(&config, clock.clone()), clock)
+    }
+
+    #[test]
+    fn opens_after_threshold_failures() {
+        let (breaker, _) = breaker(Duration::from_secs(60), Duration::from_secs(60));
+        assert!(breaker.allow());
+
+        breaker.record_failure();
+        assert!(breaker.allow());
+
+        breaker.record_failure();
+        assert!(!breaker.allow());
+    }
+
+    #[test]
+    fn success_resets_the_count() {
+        let (breaker, _) = breaker(Duration::from_secs(60), Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert!(breaker.allow());
+    }
+
+    #[test]
+    fn failures_outside_the_window_start_a_new_count() {
+        let (breaker, clock) = breaker(Duration::from_secs(10), Duration::from_secs(60));
+        breaker.record_failure();
+        clock.advance(Duration::from_secs(11));
+        breaker.record_failure();
+        assert!(breaker.allow());
+
+        // Still inside the window of the second failure
+        clock.advance(Duration::from_secs(10));
+        breaker.record_failure();
+        assert!(!breaker.allow());
+    }
+
+    #[test]
+    fn stays_open_until_the_cooldown_ends() {
+        let cooldown = Duration::from_secs(30);
+        let (breaker, clock) = breaker(Duration::from_secs(60), cooldown);
+        breaker.record_failure();
+        breaker.record_failure();
+
+        clock.advance(cooldown - Duration::from_millis(1));
+        assert!(!breaker.allow());
+        clock.advance(Duration::from_millis(1));
+        assert!(breaker.allow());
+    }
+
+    #[test]
+    fn half_open_probe_closes_or_reopens() {
+        let cooldown = Duration::from_secs(30);
+        let (breaker, clock) = breaker(Duration::from_secs(60), cooldown);
+        breaker.record_failure();
+        breaker.record_failure();
+        assert!(!breaker.allow());
+
+        // After the cooldown a single probe is let through
+        clock.advance(cooldown);
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+
+        // A failed probe reopens the circuit at once
+        breaker.record_failure();
+        assert!(!breaker.allow());
+
+        // A successful probe closes it
+        clock.advance(cooldown);
+        assert!(breaker.allow());
+        breaker.record_success();
+        assert!(breaker.allow());
+        assert!(breaker.allow());
+    }
+
+    #[test]
+    fn abandoned_probe_is_replaced_after_the_cooldown() {
+        let cooldown = Duration::from_secs(30);
+        let (breaker, clock) = breaker(Duration::from_secs(60), cooldown);
+        breaker.record_failure();
+        breaker.record_failure();
+
+        clock.advance(cooldown);
+        assert!(breaker.allow());
+        clock.advance(cooldown);
+        assert!(breaker.allow());
+    }
+}
@@ -1,19 +1,79 @@
 use crate::{
+    circuit_breaker::CircuitBreaker,
+    dates,
     error::{Error, Result},
+    identifiers::{identifier_key, normalize_identifier, validate_identifier, ProductIdentifier},
+    observer::{redact_url, RequestInfo},
+    rate_limit::RateLimiter,
+    retailer::normalize_retailer,
+    search::SearchRequest,
+    time::{Instant, SystemTime},
     types::*,
+    usage::{RateLimitState, UsageTracker},
 };
+use futures_util::stream::{self, StreamExt, TryStreamExt};
 use regex::Regex;
-use reqwest::{header::HeaderMap, Client as HttpClient};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, AUTHORIZATION, RETRY_AFTER},
+    Client as HttpClient,
+};
+use serde::Deserialize;
 use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[cfg(feature = "cache")]
+use crate::cache::{EtagCache, ResponseCache};
+#[cfg(feature = "cache")]
+use reqwest::header::{ETAG, IF_NONE_MATCH};
 
 /// SDK version
 pub const VERSION: &str = "1.0.1";
 
+/// Default timeout for `ss_test_` keys built with [`ClientBuilder`]
+const TEST_KEY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Response size above which `streaming` deserializes bodies incrementally
+#[cfg(feature = "streaming")]
+const STREAMING_THRESHOLD: u64 = 1024 * 1024;
+
 /// ShopSavvy Data API client
+///
+/// Cloning is cheap: all state lives behind a single `Arc`, so clones share the
+/// configuration, the HTTP connection pool, the rate limiter, the response
+/// cache and the usage tracker. Clone the client to hand it to spawned tasks
+/// rather than building a new one per task.
+///
+/// Dropping a request future cancels it: the underlying HTTP request is
+/// aborted, and a request dropped before it was sent is never charged. Use
+/// [`cancellable`] to tie a request to a cancellation signal.
 #[derive(Debug, Clone)]
 pub struct Client {
+    inner: Arc<ClientInner>,
+    /// Deadline applied to requests made through this handle
+    deadline: Option<Instant>,
+    /// `Accept-Language` override for requests made through this handle
+    language: Option<HeaderValue>,
+    /// `Idempotency-Key` for the next POST call, taken by the first one sent
+    idempotency_key: Arc<Mutex<Option<String>>>,
+}
+
+#[derive(Debug)]
+struct ClientInner {
     config: Config,
     client: HttpClient,
+    #[cfg(feature = "cache")]
+    cache: Option<ResponseCache>,
+    #[cfg(feature = "cache")]
+    etags: Option<EtagCache>,
+    usage: UsageTracker,
+    rate_limit_state: Mutex<Option<RateLimitState>>,
+    last_request_id: Mutex<Option<String>>,
+    rate_limiter: Option<RateLimiter>,
+    circuit_breaker: Option<CircuitBreaker>,
 }
 
 impl Client {
@@ -35,6 +95,23 @@ impl Client {
         Self::with_config(config)
     }
 
+    /// Start building a client with validated options
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use shopsavvy_sdk::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .api_key("ss_live_your_api_key_here")
+    ///     .user_agent("MyApp/1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
     /// Create a new client with custom configuration
     ///
     /// # Arguments
@@ -62,87 +139,697 @@ impl Client {
             return Err(Error::InvalidApiKey);
         }
 
-        // Create HTTP headers
+        config.validate()?;
+
+        // Create HTTP headers, applying caller-supplied ones first
         let mut headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| Error::validation(format!("Invalid header name: {}", name)))?;
+            if header_name == AUTHORIZATION {
+                return Err(Error::validation("The Authorization header is managed by the client"));
+            }
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|_| Error::validation(format!("Invalid value for header {}", name)))?;
+            headers.insert(header_name, header_value);
+        }
         headers.insert("Authorization", format!("Bearer {}", config.api_key).parse().unwrap());
         headers.insert("Content-Type", "application/json".parse().unwrap());
-        headers.insert("User-Agent", format!("ShopSavvy-Rust-SDK/{}", VERSION).parse().unwrap());
+        let user_agent = match &config.user_agent {
+            Some(custom) => format!("{} ShopSavvy-Rust-SDK/{}", custom.trim(), VERSION),
+            None => format!("ShopSavvy-Rust-SDK/{}", VERSION),
+        };
+        let user_agent = HeaderValue::from_str(&user_agent)
+            .map_err(|_| Error::validation("Invalid User-Agent value"))?;
+        headers.insert("User-Agent", user_agent);
+        if let Some(language) = &config.language {
+            let language = HeaderValue::from_str(language)
+                .map_err(|_| Error::validation(format!("Invalid language tag: {}", language)))?;
+            headers.insert(ACCEPT_LANGUAGE, language);
+        }
 
-        // Create HTTP client
-        let client = HttpClient::builder()
-            .timeout(config.timeout)
-            .default_headers(headers)
-            .build()?;
+        // Create HTTP client (browsers do not support per-request timeouts)
+        let builder = HttpClient::builder().default_headers(headers);
+        #[cfg(not(feature = "wasm"))]
+        let builder = {
+            let mut builder = builder.timeout(config.timeout);
+            if let Some(connect_timeout) = config.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            if let Some(max_idle) = config.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max_idle);
+            }
+            if let Some(idle_timeout) = config.pool_idle_timeout {
+                builder = builder.pool_idle_timeout(idle_timeout);
+            }
+            if config.http2_prior_knowledge {
+                builder = builder.http2_prior_knowledge();
+            }
+            #[cfg(feature = "native")]
+            if config.danger_accept_invalid_certs {
+                builder = builder.danger_accept_invalid_certs(true);
+            }
+            builder
+        };
+        let client = builder.build()?;
 
-        Ok(Self { config, client })
-    }
+        let rate_limiter = config.rate_limit.map(RateLimiter::new);
+        let circuit_breaker = config.circuit_breaker.as_ref().map(CircuitBreaker::new);
 
-    /// Make an HTTP request and handle the response
-    async fn request<T>(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>, body: Option<&Value>) -> Result<ApiResponse<T>>
-    where
-        T: for<'de> serde::Deserialize<'de>,
-    {
-        let url = format!("{}{}", self.config.base_url, endpoint);
+        #[cfg(feature = "cache")]
+        let cache = config.cache.as_ref().map(ResponseCache::new);
+        #[cfg(feature = "cache")]
+        let etags = config.cache.as_ref().map(EtagCache::new);
 
-        let mut request = self.client.request(method, &url);
+        let inner = ClientInner {
+            config,
+            client,
+            #[cfg(feature = "cache")]
+            cache,
+            #[cfg(feature = "cache")]
+            etags,
+            usage: UsageTracker::default(),
+            rate_limit_state: Mutex::new(None),
+            last_request_id: Mutex::new(None),
+            rate_limiter,
+            circuit_breaker,
+        };
+
+        Ok(Self {
+            inner: Arc::new(inner),
+            deadline: None,
+            language: None,
+            idempotency_key: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Get a handle whose requests must finish before `deadline`
+    ///
+    /// Each attempt is given the time left until the deadline as its timeout,
+    /// capped by the configured timeout. Once the deadline has passed, requests
+    /// fail with `Error::Timeout` without being sent. The handle shares all
+    /// state with the original client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let deadline = Instant::now() + Duration::from_millis(800);
+    /// let offers = client.with_deadline(deadline).get_current_offers(id, None, None, None).await?;
+    /// ```
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self.clone()
+        }
+    }
 
-        if let Some(params) = params {
-            request = request.query(params);
+    /// Get a handle that sends `key` as the `Idempotency-Key` of its next POST call
+    ///
+    /// Schedule calls otherwise get a fresh random key each, reused across the
+    /// retries of that call so a retried request cannot schedule twice. Supply
+    /// your own key to make a call idempotent across restarts of your process.
+    ///
+    /// The key is single-use: only the first POST call made through the handle
+    /// (or its clones) sends it, and later calls get random keys again, so a
+    /// second, different call is never deduplicated against the first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let key = format!("schedule-{}", job.id);
+    /// client.with_idempotency_key(key).schedule_product_monitoring(id, MonitoringFrequency::Daily, None).await?;
+    /// ```
+    pub fn with_idempotency_key(&self, key: impl Into<String>) -> Self {
+        Self {
+            idempotency_key: Arc::new(Mutex::new(Some(key.into()))),
+            ..self.clone()
         }
+    }
 
-        if let Some(body) = body {
-            request = request.json(body);
+    /// Get a handle whose requests ask for product data in another language
+    ///
+    /// Overrides the configured [`Config::with_language`] for calls made through
+    /// the returned handle, which shares all state with the original client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let german = client.with_language("de-DE")?;
+    /// let product = german.get_product_details("012345678901", None, None).await?;
+    /// ```
+    pub fn with_language(&self, language: &str) -> Result<Self> {
+        if !is_language_tag(language) {
+            return Err(Error::validation(format!("Invalid language tag: {}", language)));
         }
+        let language = HeaderValue::from_str(language)
+            .map_err(|_| Error::validation(format!("Invalid language tag: {}", language)))?;
+        Ok(Self {
+            language: Some(language),
+            ..self.clone()
+        })
+    }
+
+    /// Send an HTTP request and return the successful response body
+    async fn fetch(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>, body: Option<&Value>) -> Result<String> {
+        let (response_text, _) = self.fetch_with_headers(method, endpoint, params, body).await?;
+        Ok(response_text)
+    }
+
+    /// Send an HTTP request and return the successful response body and headers
+    async fn fetch_with_headers(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>, body: Option<&Value>) -> Result<(String, HeaderMap)> {
+        #[cfg(feature = "cache")]
+        let etag_key = self.etag_key(&method, endpoint, params);
+
+        let response = match self.send(method, endpoint, params, body).await? {
+            Fetched::NotModified(response_text, headers) => return Ok((response_text, headers)),
+            Fetched::Response(response) => response,
+        };
 
-        let response = request.send().await?;
         let status_code = response.status().as_u16();
+        let headers = response.headers().clone();
+        #[cfg(feature = "cache")]
+        let etag = headers.get(ETAG).and_then(|v| v.to_str().ok()).map(str::to_string);
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            let error_message = if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&error_text) {
-                error_json["error"].as_str().unwrap_or(&error_text).to_string()
-            } else {
-                error_text
+        let response_text = self.read_body(response).await?;
+        self.record_meta(&response_text);
+
+        // The API can report a failure with a 2xx status and `success: false`
+        if let Some(message) = soft_failure(&response_text) {
+            return Err(Error::Api { message, status_code });
+        }
+
+        #[cfg(feature = "cache")]
+        if let (Some(etags), Some(key), Some(etag)) = (&self.inner.etags, etag_key, etag) {
+            etags.insert(key, etag, response_text.clone());
+        }
+
+        Ok((response_text, headers))
+    }
+
+    /// Send an HTTP request and return the successful response, unread
+    ///
+    /// Transient failures are retried according to the configured `RetryConfig`.
+    async fn send(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>, body: Option<&Value>) -> Result<Fetched> {
+        let url = join_url(&self.inner.config.base_url, endpoint);
+        let max_retries = self.inner.config.retry.as_ref().map(|r| r.max_retries).unwrap_or(0);
+        let mut attempt = 0;
+
+        // One key per logical call, reused by its retries
+        let idempotency_key = (method == reqwest::Method::POST)
+            .then(|| self.idempotency_key.lock().unwrap().take().unwrap_or_else(|| uuid::Uuid::new_v4().to_string()));
+
+        let request_id = match &self.inner.config.request_id_generator {
+            Some(generator) => generator.generate(),
+            None => uuid::Uuid::new_v4().to_string(),
+        };
+
+        // Read once so an eviction between sending and a 304 cannot lose the body
+        #[cfg(feature = "cache")]
+        let stored = self
+            .etag_key(&method, endpoint, params)
+            .and_then(|key| self.inner.etags.as_ref()?.get(&key));
+
+        loop {
+            let mut request = self.inner.client.request(method.clone(), &url);
+
+            if let Some(params) = params {
+                request = request.query(params);
+            }
+
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+
+            #[cfg(feature = "cache")]
+            if let Some((etag, _)) = &stored {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+
+            if let Some(language) = &self.language {
+                request = request.header(ACCEPT_LANGUAGE, language.clone());
+            }
+
+            if let Some(key) = &idempotency_key {
+                request = request.header("Idempotency-Key", key);
+            }
+
+            request = request.header("X-Request-Id", &request_id);
+
+            if let Some(deadline) = self.deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(Error::Timeout);
+                }
+                #[cfg(not(feature = "wasm"))]
+                {
+                    request = request.timeout(remaining.min(self.inner.config.timeout));
+                }
+            }
+
+            let request = request.build()?;
+
+            if let Some(observer) = &self.inner.config.observer {
+                observer.notify(&RequestInfo {
+                    method: request.method().to_string(),
+                    url: redact_url(request.url().as_str()),
+                    body: body.cloned(),
+                    attempt,
+                    request_id: request_id.clone(),
+                });
+            }
+
+            if self.inner.config.dry_run {
+                return Err(Error::DryRun {
+                    url: redact_url(request.url().as_str()),
+                    body: body.map(|b| b.to_string()),
+                });
+            }
+
+            let breaker = self.inner.circuit_breaker.as_ref();
+            if breaker.is_some_and(|breaker| !breaker.allow()) {
+                return Err(Error::CircuitOpen);
+            }
+
+            if let Some(limiter) = &self.inner.rate_limiter {
+                limiter.acquire().await;
+            }
+
+            #[cfg(feature = "metrics")]
+            let started = Instant::now();
+
+            #[cfg(feature = "replay")]
+            let result = match &self.inner.config.cassette {
+                Some(cassette) => cassette.execute(&self.inner.client, request).await?,
+                None => self.inner.client.execute(request).await,
+            };
+            #[cfg(not(feature = "replay"))]
+            let result = self.inner.client.execute(request).await;
+
+            #[cfg(feature = "metrics")]
+            crate::telemetry::record_request(
+                endpoint,
+                result.as_ref().ok().map(|response| response.status().as_u16()),
+                started.elapsed(),
+            );
+
+            let response = match result {
+                Ok(response) => response,
+                Err(err) => {
+                    let transient = is_transient(&err);
+                    if let Some(breaker) = breaker.filter(|_| transient) {
+                        breaker.record_failure();
+                    }
+                    if attempt < max_retries && transient {
+                        self.wait_before_retry(attempt, None).await?;
+                        attempt += 1;
+                        continue;
+                    }
+                    if err.is_timeout() && self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                        return Err(Error::Timeout);
+                    }
+                    return Err(err.into());
+                }
             };
-            return Err(Error::from_status_code(status_code, error_message));
+            let status_code = response.status().as_u16();
+            if let Some(state) = RateLimitState::from_headers(response.headers()) {
+                *self.inner.rate_limit_state.lock().unwrap() = Some(state);
+            }
+
+            // The server's ID when it echoes one, so it can be quoted to support
+            let echoed = response.headers().get("x-request-id").and_then(|v| v.to_str().ok());
+            *self.inner.last_request_id.lock().unwrap() = Some(echoed.unwrap_or(&request_id).to_string());
+
+            if let Some(breaker) = breaker {
+                if status_code >= 500 {
+                    breaker.record_failure();
+                } else {
+                    breaker.record_success();
+                }
+            }
+
+            // Unchanged since the last response: serve the stored body
+            #[cfg(feature = "cache")]
+            if status_code == 304 {
+                if let Some((_, cached)) = &stored {
+                    return Ok(Fetched::NotModified(cached.clone(), response.headers().clone()));
+                }
+            }
+
+            if !response.status().is_success() {
+                let retry_after = retry_after(response.headers());
+                let error_text = self.read_body(response).await.unwrap_or_default();
+                // Error bodies can carry `meta` too, e.g. the credits left on a 429
+                self.record_meta(&error_text);
+                let error_message = if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&error_text) {
+                    error_json["error"].as_str().unwrap_or(&error_text).to_string()
+                } else {
+                    error_text
+                };
+
+                // Backing off cannot help once the account has no credits left
+                if status_code == 429 && self.last_credits_remaining() == Some(0) {
+                    return Err(Error::CreditsExhausted {
+                        message: error_message,
+                        status_code,
+                    });
+                }
+
+                if attempt < max_retries && (status_code == 429 || status_code >= 500) {
+                    self.wait_before_retry(attempt, retry_after).await?;
+                    attempt += 1;
+                    continue;
+                }
+
+                return Err(Error::from_status_code(status_code, error_message));
+            }
+
+            return Ok(Fetched::Response(response));
+        }
+    }
+
+    /// Read a response body as text, stopping once it exceeds the configured limit
+    async fn read_body(&self, response: reqwest::Response) -> Result<String> {
+        let limit = self.inner.config.max_response_bytes;
+        if response.content_length().is_some_and(|len| len > limit as u64) {
+            return Err(Error::ResponseTooLarge { limit });
+        }
+
+        // Browsers buffer the body anyway, so only check the size afterwards
+        #[cfg(feature = "wasm")]
+        let body = response.bytes().await?.to_vec();
+
+        #[cfg(not(feature = "wasm"))]
+        let body = {
+            let mut response = response;
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                if body.len() + chunk.len() > limit {
+                    return Err(Error::ResponseTooLarge { limit });
+                }
+                body.extend_from_slice(&chunk);
+            }
+            body
+        };
+
+        if body.len() > limit {
+            return Err(Error::ResponseTooLarge { limit });
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Sleep before the next retry attempt, preferring the server's `Retry-After`
+    ///
+    /// `Retry-After` is capped at the configured `max_delay`. Fails with
+    /// `Error::Timeout` without sleeping when the delay would run past the
+    /// handle's deadline.
+    async fn wait_before_retry(&self, attempt: u32, retry_after: Option<Duration>) -> Result<()> {
+        let retry = self.inner.config.retry.as_ref();
+        let delay = match (retry_after, retry) {
+            (Some(retry_after), Some(retry)) => retry_after.min(retry.max_delay),
+            (Some(retry_after), None) => retry_after,
+            (None, retry) => retry.map(|r| r.backoff(attempt)).unwrap_or_default(),
+        };
+        if self
+            .deadline
+            .is_some_and(|deadline| delay >= deadline.saturating_duration_since(Instant::now()))
+        {
+            return Err(Error::Timeout);
+        }
+        match retry.and_then(|r| r.clock.as_ref()) {
+            Some(clock) => clock.advance(delay),
+            None => crate::time::sleep(delay).await,
         }
+        Ok(())
+    }
+
+    /// Record the credit usage reported by a response
+    fn record_meta(&self, response_text: &str) {
+        if let Ok(envelope) = serde_json::from_str::<MetaEnvelope>(response_text) {
+            if let Some(meta) = envelope.meta {
+                self.record_usage(&meta);
+            }
+        }
+    }
+
+    /// Record a response's `meta` object in the usage tracker and rate-limit state
+    fn record_usage(&self, meta: &ApiMeta) {
+        self.inner.usage.record(meta);
+        #[cfg(feature = "metrics")]
+        crate::telemetry::record_credits_remaining(meta.credits_remaining);
+        if let Some(remaining) = meta.rate_limit_remaining {
+            self.update_rate_limit_remaining(remaining);
+        }
+    }
+
+    /// Update the remaining request count from a response's `meta` object
+    fn update_rate_limit_remaining(&self, remaining: i32) {
+        let now = SystemTime::now();
+        let mut state = self.inner.rate_limit_state.lock().unwrap();
+        let state = state.get_or_insert_with(|| RateLimitState {
+            remaining,
+            limit: None,
+            reset_at: None,
+            observed_at: now,
+        });
+        state.remaining = remaining;
+        state.observed_at = now;
+    }
+
+    /// Get the rate-limit position reported by the most recent response
+    ///
+    /// Updated after every response from the `X-RateLimit-*` headers and the
+    /// `meta.rate_limit_remaining` field. `None` until a response reports it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if let Some(state) = client.rate_limit_state() {
+    ///     println!("{} requests remaining", state.remaining);
+    /// }
+    /// ```
+    pub fn rate_limit_state(&self) -> Option<RateLimitState> {
+        self.inner.rate_limit_state.lock().unwrap().clone()
+    }
+
+    /// Request ID of the most recent response
+    ///
+    /// This is the `X-Request-Id` the server echoed back, or the one sent when
+    /// the response did not include it. `None` until a response is received.
+    pub fn last_request_id(&self) -> Option<String> {
+        self.inner.last_request_id.lock().unwrap().clone()
+    }
+
+    /// Credits remaining as reported by the most recent response, if any
+    pub fn last_credits_remaining(&self) -> Option<i32> {
+        self.inner.usage.latest().map(|sample| sample.credits_remaining)
+    }
 
-        let response_text = response.text().await?;
-        let api_response: ApiResponse<T> = serde_json::from_str(&response_text)?;
+    /// Get the credit usage recorded from responses made by this client
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for sample in client.usage_tracker().samples() {
+    ///     println!("{:?}: {} credits left", sample.recorded_at, sample.credits_remaining);
+    /// }
+    /// ```
+    pub fn usage_tracker(&self) -> &UsageTracker {
+        &self.inner.usage
+    }
+
+    /// Most identifiers the batch methods accept in one call
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for chunk in identifiers.chunks(client.max_batch_size()) {
+    ///     let products = client.get_product_details_batch(chunk, None).await?;
+    /// }
+    /// ```
+    pub fn max_batch_size(&self) -> usize {
+        self.inner.config.max_batch_size
+    }
+
+    /// Make an HTTP request and handle the response
+    async fn request<T>(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>, body: Option<&Value>) -> Result<ApiResponse<T>>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        let response_text = self.fetch(method, endpoint, params, body).await?;
+        let api_response: ApiResponse<T> = parse_json(&response_text)?;
 
         Ok(api_response)
     }
 
     /// Make a request and return raw result (for ProductSearchResult)
-    async fn request_raw<T>(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>) -> Result<T>
+    pub(crate) async fn request_raw<T>(&self, method: reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>) -> Result<T>
     where
         T: for<'de> serde::Deserialize<'de>,
     {
-        let url = format!("{}{}", self.config.base_url, endpoint);
+        let response_text = self.fetch(method, endpoint, params, None).await?;
+        let result: T = parse_json(&response_text)?;
+
+        Ok(result)
+    }
 
-        let mut request = self.client.request(method, &url);
+    /// Validate a batch against the configured size limit and join it for the `ids` parameter
+    fn join_batch(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>) -> Result<String> {
+        let identifiers: Vec<ProductIdentifier> = identifiers.into_iter().map(Into::into).collect();
+        let max_batch_size = self.inner.config.max_batch_size;
+        if identifiers.len() > max_batch_size {
+            return Err(Error::validation(format!(
+                "Batch of {} identifiers exceeds the limit of {}; split it into chunks of at most {}",
+                identifiers.len(),
+                max_batch_size,
+                max_batch_size
+            )));
+        }
+        let normalized: Vec<Cow<'_, str>> = identifiers.iter().map(|identifier| self.normalized(identifier.as_str())).collect();
+        join_identifiers(&normalized)
+    }
+
+    /// Normalize and validate a single identifier, returning the value sent to the API
+    fn request_identifier(&self, identifier: impl Into<ProductIdentifier>) -> Result<String> {
+        let identifier: ProductIdentifier = identifier.into();
+        let identifier = self.normalized(identifier.as_str());
+        validate_identifier(&identifier)?;
+        Ok(identifier.into_owned())
+    }
 
-        if let Some(params) = params {
-            request = request.query(params);
+    /// Normalize an identifier unless disabled in the configuration
+    fn normalized<'a>(&self, identifier: &'a str) -> Cow<'a, str> {
+        if self.inner.config.normalize_identifiers {
+            normalize_identifier(identifier)
+        } else {
+            Cow::Borrowed(identifier)
         }
+    }
 
-        let response = request.send().await?;
-        let status_code = response.status().as_u16();
+    /// Make a GET request for a potentially large collection
+    ///
+    /// With the `streaming` feature, bodies that are large or of unknown length
+    /// are deserialized while they download instead of being buffered first.
+    async fn request_large<T>(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<ApiResponse<T>>
+    where
+        T: for<'de> serde::Deserialize<'de> + Send + 'static,
+    {
+        #[cfg(feature = "streaming")]
+        {
+            let response = match self.send(reqwest::Method::GET, endpoint, Some(params), None).await? {
+                Fetched::NotModified(response_text, _) => return parse_json(&response_text),
+                Fetched::Response(response) => response,
+            };
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            let error_message = if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&error_text) {
-                error_json["error"].as_str().unwrap_or(&error_text).to_string()
+            // Small bodies are cheaper to buffer than to hand to a blocking parser
+            let status_code = response.status().as_u16();
+            let limit = self.inner.config.max_response_bytes;
+            let repr: ApiResponseRepr<T> = if response.content_length().is_some_and(|len| len <= STREAMING_THRESHOLD) {
+                parse_json(&self.read_body(response).await?)?
             } else {
-                error_text
+                crate::streaming::deserialize_response(response, limit).await?
             };
-            return Err(Error::from_status_code(status_code, error_message));
+
+            if let Some(meta) = &repr.meta {
+                self.record_usage(meta);
+            }
+            if let Some(message) = failure_message(Some(repr.success), repr.message.clone(), repr.error.clone()) {
+                return Err(Error::Api { message, status_code });
+            }
+            ApiResponse::try_from(repr).map_err(|err| Error::Json(serde::de::Error::custom(err)))
         }
 
-        let response_text = response.text().await?;
-        let result: T = serde_json::from_str(&response_text)?;
+        #[cfg(not(feature = "streaming"))]
+        {
+            self.request(reqwest::Method::GET, endpoint, Some(params), None).await
+        }
+    }
 
-        Ok(result)
+    /// Make a GET request, serving the body from the response cache when enabled
+    async fn request_cached<T>(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<ApiResponse<T>>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.inner.cache {
+            let key = self.cache_key(endpoint, params);
+            let response_text = match cache.get(&key) {
+                Some(cached) => cached,
+                None => {
+                    let fresh = self.fetch(reqwest::Method::GET, endpoint, Some(params), None).await?;
+                    cache.insert(key, fresh.clone());
+                    fresh
+                }
+            };
+            return parse_json(&response_text);
+        }
+
+        self.request(reqwest::Method::GET, endpoint, Some(params), None).await
+    }
+
+    /// ETag cache key for a request, if its response may be revalidated
+    ///
+    /// Only product details GETs are stored: offers and history are never
+    /// cached, and their bodies can be too large to keep around.
+    #[cfg(feature = "cache")]
+    fn etag_key(&self, method: &reqwest::Method, endpoint: &str, params: Option<&[(&str, &str)]>) -> Option<String> {
+        let revalidated = self.inner.etags.is_some() && *method == reqwest::Method::GET && endpoint == "/products";
+        revalidated.then(|| self.cache_key(endpoint, params.unwrap_or(&[])))
+    }
+
+    /// Build a cache key from an endpoint, its query parameters and the language override
+    #[cfg(feature = "cache")]
+    fn cache_key(&self, endpoint: &str, params: &[(&str, &str)]) -> String {
+        let query: Vec<String> = params.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        let language = self.language.as_ref().and_then(|l| l.to_str().ok()).unwrap_or_default();
+        format!("{}?{}#{}", endpoint, query.join("&"), language)
+    }
+
+    /// Clear all cached product details responses and stored ETags
+    #[cfg(feature = "cache")]
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.inner.cache {
+            cache.clear();
+        }
+        if let Some(etags) = &self.inner.etags {
+            etags.clear();
+        }
+    }
+
+    /// Make a GET request to any endpoint and return the untyped JSON response
+    ///
+    /// This is an escape hatch for endpoints and query parameters the SDK does
+    /// not model yet. Parameters are passed through as-is, bypassing the typed
+    /// validation of the dedicated methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path relative to the base URL, e.g. `/products/offers`
+    /// * `params` - Query parameters
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let value = client.get_raw("/products/offers", &[("ids", "012345678901"), ("include_used", "true")]).await?;
+    /// ```
+    pub async fn get_raw(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<Value> {
+        self.request_raw(reqwest::Method::GET, endpoint, Some(params)).await
+    }
+
+    /// Make a GET request to any endpoint and return the JSON response with its headers
+    ///
+    /// Like [`get_raw`](Client::get_raw), but also exposes the raw response
+    /// headers for debugging rate limits and caching. Header names follow HTTP
+    /// conventions and are always lowercase, e.g. `x-ratelimit-remaining`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (value, headers) = client.get_with_headers("/usage", &[]).await?;
+    /// println!("{:?}", headers.get("x-ratelimit-remaining"));
+    /// ```
+    pub async fn get_with_headers(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<(Value, HeaderMap)> {
+        let (response_text, headers) = self.fetch_with_headers(reqwest::Method::GET, endpoint, Some(params), None).await?;
+        Ok((parse_json(&response_text)?, headers))
     }
 
     /// Search for products by keyword
@@ -179,20 +866,74 @@ impl Client {
         self.request_raw(reqwest::Method::GET, "/products/search", Some(&params)).await
     }
 
+    /// Search for products using cursor pagination
+    ///
+    /// Cursors keep results stable while the underlying data changes, unlike
+    /// offsets. Pass `None` for the first page, then the previous page's
+    /// [`next_cursor`](ProductSearchResult::next_cursor) until it is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search query or keyword
+    /// * `cursor` - Cursor returned by the previous page
+    /// * `limit` - Optional maximum number of results per page
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut cursor = None;
+    /// loop {
+    ///     let page = client.search_products_cursor("usb c cable", cursor.as_deref(), Some(100)).await?;
+    ///     export(&page.data)?;
+    ///     match page.next_cursor() {
+    ///         Some(next) => cursor = Some(next.to_string()),
+    ///         None => break,
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_products_cursor(&self, query: &str, cursor: Option<&str>, limit: Option<i32>) -> Result<ProductSearchResult> {
+        let mut params = vec![("q", query)];
+
+        if let Some(c) = cursor {
+            params.push(("cursor", c));
+        }
+
+        let limit_str: String;
+        if let Some(l) = limit {
+            limit_str = l.to_string();
+            params.push(("limit", &limit_str));
+        }
+
+        self.request_raw(reqwest::Method::GET, "/products/search", Some(&params)).await
+    }
+
+    /// Start a product search with filters
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let results = client.search("headphones").brand("Sony").max_price(200.0).send().await?;
+    /// ```
+    pub fn search(&self, query: &str) -> SearchRequest<'_> {
+        SearchRequest::new(self, query)
+    }
+
     /// Look up product details by identifier
     ///
     /// # Arguments
     ///
     /// * `identifier` - Product identifier (barcode, ASIN, URL, model number, or ShopSavvy product ID)
     /// * `format` - Optional output format
+    /// * `country` - Optional ISO 3166-1 alpha-2 country code, e.g. `"US"` or `"GB"`
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// let product = client.get_product_details("012345678901", None).await?;
+    /// let product = client.get_product_details("012345678901", None, None).await?;
     /// println!("Product: {}", product.data[0].title);
     /// ```
-    pub async fn get_product_details(&self, identifier: &str, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductDetails>>> {
+    pub async fn get_product_details(&self, identifier: impl Into<ProductIdentifier>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductDetails>>> {
+        let identifier = &*self.request_identifier(identifier)?;
         let mut params = vec![("ids", identifier)];
 
         let format_str;
@@ -201,7 +942,13 @@ impl Client {
             params.push(("format", &format_str));
         }
 
-        self.request(reqwest::Method::GET, "/products", Some(&params), None).await
+        let country_str;
+        if let Some(code) = country {
+            country_str = country_code(code)?;
+            params.push(("country", &country_str));
+        }
+
+        self.request_cached("/products", &params).await
     }
 
     /// Look up details for multiple products
@@ -219,8 +966,8 @@ impl Client {
     ///     None
     /// ).await?;
     /// ```
-    pub async fn get_product_details_batch(&self, identifiers: &[&str], format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductDetails>>> {
-        let identifiers_str = identifiers.join(",");
+    pub async fn get_product_details_batch(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductDetails>>> {
+        let identifiers_str = self.join_batch(identifiers)?;
         let mut params = vec![("ids", identifiers_str.as_str())];
 
         let format_str;
@@ -229,30 +976,150 @@ impl Client {
             params.push(("format", &format_str));
         }
 
-        self.request(reqwest::Method::GET, "/products", Some(&params), None).await
+        self.request_cached("/products", &params).await
     }
 
-    /// Get current offers for a product
+    /// Look up a product, returning `Ok(None)` when it does not exist
     ///
-    /// # Arguments
+    /// A 404 response or empty `data` maps to `None`; any other failure is
+    /// still returned as an error.
     ///
-    /// * `identifier` - Product identifier
-    /// * `retailer` - Optional retailer to filter by
-    /// * `format` - Optional output format
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match client.try_get_product_details("012345678901").await? {
+    ///     Some(product) => println!("Found {}", product.title),
+    ///     None => println!("No such product"),
+    /// }
+    /// ```
+    pub async fn try_get_product_details(&self, identifier: impl Into<ProductIdentifier>) -> Result<Option<ProductDetails>> {
+        let response = not_found_as_none(self.get_product_details(identifier, None, None).await)?;
+        Ok(response.and_then(|response| response.data.into_iter().next()))
+    }
+
+    /// Get current offers for a product, returning `Ok(None)` when it does not exist
+    ///
+    /// A 404 response or empty `data` maps to `None`; any other failure is
+    /// still returned as an error.
+    pub async fn try_get_current_offers(&self, identifier: impl Into<ProductIdentifier>, retailer: Option<&str>) -> Result<Option<ProductWithOffers>> {
+        let response = not_found_as_none(self.get_current_offers(identifier, retailer, None, None).await)?;
+        Ok(response.and_then(|response| response.data.into_iter().next()))
+    }
+
+    /// Look up multiple products, keyed by the identifiers requested
+    ///
+    /// Each input identifier maps to the product it matches by ShopSavvy ID,
+    /// barcode, ASIN, model or MPN, or to `None` when no product matched.
+    /// Matching follows [`Client::resolve_product_ids`]: barcode formatting is
+    /// ignored and product URLs are looked up one at a time.
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// let result = client.get_current_offers("012345678901", None, None).await?;
-    /// for product in result.data {
-    ///     println!("Product: {}", product.title);
-    ///     for offer in product.offers {
-    ///         println!("  {}: ${:?}", offer.retailer, offer.price);
+    /// let products = client.get_product_details_map(&["012345678901", "B08N5WRWNW"]).await?;
+    /// for (identifier, product) in &products {
+    ///     match product {
+    ///         Some(product) => println!("{}: {}", identifier, product.title),
+    ///         None => println!("{}: not found", identifier),
     ///     }
     /// }
     /// ```
-    pub async fn get_current_offers(&self, identifier: &str, retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
-        let mut params = vec![("ids", identifier)];
+    pub async fn get_product_details_map(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>) -> Result<HashMap<String, Option<ProductDetails>>> {
+        let identifiers: Vec<ProductIdentifier> = identifiers.into_iter().map(Into::into).collect();
+        Ok(self
+            .lookup_by_identifier(identifiers)
+            .await?
+            .into_iter()
+            .map(|(identifier, product)| (identifier.to_string(), product))
+            .collect())
+    }
+
+    /// Resolve any product identifier to its canonical ShopSavvy product ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let id = client.resolve_product_id("B08N5WRWNW").await?;
+    /// ```
+    pub async fn resolve_product_id(&self, identifier: impl Into<ProductIdentifier>) -> Result<String> {
+        let identifier: ProductIdentifier = identifier.into();
+        let response = self.get_product_details(&identifier, None, None).await?;
+        response
+            .data
+            .into_iter()
+            .next()
+            .map(|product| product.shopsavvy)
+            .ok_or_else(|| Error::not_found(format!("No product found for {}", identifier)))
+    }
+
+    /// Resolve multiple identifiers to canonical ShopSavvy product IDs
+    ///
+    /// Returns a map from each input identifier to its ShopSavvy ID. Identifiers
+    /// that did not match any product are omitted. Barcodes match regardless of
+    /// dashes or UPC-A/EAN-13 zero-padding; product URLs are resolved one at a
+    /// time since batch results cannot be matched back to them.
+    pub async fn resolve_product_ids(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>) -> Result<HashMap<String, String>> {
+        let identifiers: Vec<ProductIdentifier> = identifiers.into_iter().map(Into::into).collect();
+        Ok(self
+            .lookup_by_identifier(identifiers)
+            .await?
+            .into_iter()
+            .filter_map(|(identifier, product)| Some((identifier.to_string(), product?.shopsavvy)))
+            .collect())
+    }
+
+    /// Look up each identifier, pairing it with the product it refers to
+    ///
+    /// Non-URL identifiers go out in one batch request and are matched with
+    /// [`ProductDetails::matches_identifier`]; URLs are looked up individually.
+    async fn lookup_by_identifier(&self, identifiers: Vec<ProductIdentifier>) -> Result<Vec<(ProductIdentifier, Option<ProductDetails>)>> {
+        join_identifiers(&identifiers)?;
+        let (urls, others): (Vec<_>, Vec<_>) =
+            identifiers.into_iter().partition(|identifier| matches!(identifier, ProductIdentifier::Url(_)));
+
+        let mut found = Vec::with_capacity(urls.len() + others.len());
+        if !others.is_empty() {
+            let response = self.get_product_details_batch(&others, None).await?;
+            for identifier in others {
+                let product = response.data.iter().find(|p| p.matches_identifier(identifier.as_str())).cloned();
+                found.push((identifier, product));
+            }
+        }
+        let by_url: Vec<_> = stream::iter(urls)
+            .map(|identifier| async move {
+                let product = self.try_get_product_details(&identifier).await?;
+                Ok::<_, Error>((identifier, product))
+            })
+            .buffer_unordered(self.inner.config.fan_out_concurrency)
+            .try_collect()
+            .await?;
+        found.extend(by_url);
+        Ok(found)
+    }
+
+    /// Get current offers for a product
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - Product identifier
+    /// * `retailer` - Optional retailer to filter by
+    /// * `format` - Optional output format
+    /// * `country` - Optional ISO 3166-1 alpha-2 country code, e.g. `"US"` or `"GB"`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.get_current_offers("012345678901", None, None, Some("GB")).await?;
+    /// for product in result.data {
+    ///     println!("Product: {}", product.title);
+    ///     for offer in product.offers {
+    ///         println!("  {}: ${:?}", offer.retailer, offer.price);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_current_offers(&self, identifier: impl Into<ProductIdentifier>, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        let identifier = &*self.request_identifier(identifier)?;
+        let mut params = vec![("ids", identifier)];
 
         if let Some(ret) = retailer {
             params.push(("retailer", ret));
@@ -264,12 +1131,117 @@ impl Client {
             params.push(("format", &format_str));
         }
 
+        let country_str;
+        if let Some(code) = country {
+            country_str = country_code(code)?;
+            params.push(("country", &country_str));
+        }
+
         self.request(reqwest::Method::GET, "/products/offers", Some(&params), None).await
     }
 
+    /// Get current offers for a product with each offer's price history embedded
+    ///
+    /// Sends `include_history=true` so `Offer::history` is populated in the same
+    /// call, avoiding a second request to the history endpoint. Embedded history
+    /// may cost additional credits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.get_current_offers_with_history("012345678901", None).await?;
+    /// for offer in &result.data[0].offers {
+    ///     println!("{:?}: {} points", offer.retailer, offer.history_entries().len());
+    /// }
+    /// ```
+    pub async fn get_current_offers_with_history(&self, identifier: impl Into<ProductIdentifier>, retailer: Option<&str>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        let identifier = &*self.request_identifier(identifier)?;
+        let mut params = vec![("ids", identifier), ("include_history", "true")];
+
+        if let Some(ret) = retailer {
+            params.push(("retailer", ret));
+        }
+
+        self.request(reqwest::Method::GET, "/products/offers", Some(&params), None).await
+    }
+
+    /// Get current offers for a product filtered by item condition
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - Product identifier
+    /// * `condition` - Only return offers in this condition
+    /// * `format` - Optional output format
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.get_current_offers_by_condition("012345678901", Condition::Used, None).await?;
+    /// ```
+    pub async fn get_current_offers_by_condition(&self, identifier: impl Into<ProductIdentifier>, condition: Condition, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        let identifier = &*self.request_identifier(identifier)?;
+        let condition_str = condition.to_string();
+        let mut params = vec![("ids", identifier), ("condition", condition_str.as_str())];
+
+        let format_str;
+        if let Some(fmt) = format {
+            format_str = fmt.to_string();
+            params.push(("format", &format_str));
+        }
+
+        self.request(reqwest::Method::GET, "/products/offers", Some(&params), None).await
+    }
+
+    /// Get a single retailer's current offer for a product
+    ///
+    /// Fetches offers filtered by `retailer` and returns the matching offer,
+    /// comparing normalized retailer names, or `None` when the retailer has no
+    /// offer. When the retailer lists several offers the first is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if let Some(offer) = client.get_retailer_offer("012345678901", "amazon").await? {
+    ///     println!("Amazon: {:?}", offer.price);
+    /// }
+    /// ```
+    pub async fn get_retailer_offer(&self, identifier: impl Into<ProductIdentifier>, retailer: &str) -> Result<Option<Offer>> {
+        let response = self.get_current_offers(identifier, Some(retailer), None, None).await?;
+        let slug = normalize_retailer(retailer);
+        Ok(response
+            .data
+            .into_iter()
+            .flat_map(|product| product.offers)
+            .find(|offer| offer.retailer.as_deref().is_some_and(|r| normalize_retailer(r) == slug)))
+    }
+
+    /// Get the names of retailers currently offering a product
+    ///
+    /// Fetches current offers and returns the retailer names only, sorted and
+    /// deduplicated case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let retailers = client.get_retailers_for("012345678901").await?;
+    /// println!("Available at: {}", retailers.join(", "));
+    /// ```
+    pub async fn get_retailers_for(&self, identifier: impl Into<ProductIdentifier>) -> Result<Vec<String>> {
+        let response = self.get_current_offers(identifier, None, None, None).await?;
+        let mut retailers: Vec<String> = response
+            .data
+            .iter()
+            .flat_map(|product| product.retailers())
+            .map(str::to_string)
+            .collect();
+        retailers.sort_by_cached_key(|retailer| retailer.to_lowercase());
+        retailers.dedup_by_key(|retailer| retailer.to_lowercase());
+        Ok(retailers)
+    }
+
     /// Get current offers for multiple products
-    pub async fn get_current_offers_batch(&self, identifiers: &[&str], retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
-        let identifiers_str = identifiers.join(",");
+    pub async fn get_current_offers_batch(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>, retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<ProductWithOffers>>> {
+        let identifiers_str = self.join_batch(identifiers)?;
         let mut params = vec![("ids", identifiers_str.as_str())];
 
         if let Some(ret) = retailer {
@@ -282,7 +1254,7 @@ impl Client {
             params.push(("format", &format_str));
         }
 
-        self.request(reqwest::Method::GET, "/products/offers", Some(&params), None).await
+        self.request_large("/products/offers", &params).await
     }
 
     /// Get price history for a product
@@ -294,6 +1266,7 @@ impl Client {
     /// * `end_date` - End date (YYYY-MM-DD format)
     /// * `retailer` - Optional retailer to filter by
     /// * `format` - Optional output format
+    /// * `country` - Optional ISO 3166-1 alpha-2 country code, e.g. `"US"` or `"GB"`
     ///
     /// # Example
     ///
@@ -303,10 +1276,12 @@ impl Client {
     ///     "2024-01-01",
     ///     "2024-01-31",
     ///     None,
+    ///     None,
     ///     None
     /// ).await?;
     /// ```
-    pub async fn get_price_history(&self, identifier: &str, start_date: &str, end_date: &str, retailer: Option<&str>, format: Option<OutputFormat>) -> Result<ApiResponse<Vec<OfferWithHistory>>> {
+    pub async fn get_price_history(&self, identifier: impl Into<ProductIdentifier>, start_date: &str, end_date: &str, retailer: Option<&str>, format: Option<OutputFormat>, country: Option<&str>) -> Result<ApiResponse<Vec<OfferWithHistory>>> {
+        let identifier = &*self.request_identifier(identifier)?;
         let mut params = vec![
             ("ids", identifier),
             ("start_date", start_date),
@@ -323,7 +1298,236 @@ impl Client {
             params.push(("format", &format_str));
         }
 
-        self.request(reqwest::Method::GET, "/products/offers/history", Some(&params), None).await
+        let country_str;
+        if let Some(code) = country {
+            country_str = country_code(code)?;
+            params.push(("country", &country_str));
+        }
+
+        self.request_large("/products/offers/history", &params).await
+    }
+
+    /// Get price history for several products over the same date range
+    ///
+    /// Sends one request per identifier, at most `Config::fan_out_concurrency`
+    /// (4 by default) at a time, so the configured rate limit and retries apply
+    /// to each. Like the other batch methods it accepts at most
+    /// [`Client::max_batch_size`] identifiers. Spellings of the same identifier
+    /// (such as a dashed barcode and its plain form) are fetched once, and every
+    /// input is a key in the result. Any failed request fails the whole call.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let history = client.get_price_history_batch(&ids, "2024-01-01", "2024-01-31", None).await?;
+    /// for (id, offers) in &history {
+    ///     println!("{}: {} offers", id, offers.len());
+    /// }
+    /// ```
+    pub async fn get_price_history_batch(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>, start_date: &str, end_date: &str, retailer: Option<&str>) -> Result<HashMap<String, Vec<OfferWithHistory>>> {
+        let identifiers: Vec<ProductIdentifier> = identifiers.into_iter().map(Into::into).collect();
+        self.join_batch(&identifiers)?;
+
+        let mut distinct: HashMap<String, &ProductIdentifier> = HashMap::new();
+        for identifier in &identifiers {
+            distinct.entry(identifier_key(identifier.as_str())).or_insert(identifier);
+        }
+        let histories: HashMap<String, Vec<OfferWithHistory>> = stream::iter(distinct)
+            .map(|(key, identifier)| async move {
+                let history = self.get_price_history(identifier, start_date, end_date, retailer, None, None).await?;
+                Ok::<_, Error>((key, history.data))
+            })
+            .buffer_unordered(self.inner.config.fan_out_concurrency)
+            .try_collect()
+            .await?;
+        Ok(identifiers
+            .iter()
+            .map(|identifier| {
+                let history = histories.get(&identifier_key(identifier.as_str())).cloned().unwrap_or_default();
+                (identifier.to_string(), history)
+            })
+            .collect())
+    }
+
+    /// Get price history recorded after the last sync date
+    ///
+    /// Requests the range from the day after `last_synced_date` through today
+    /// and keeps only entries dated after it. Offers without new entries are
+    /// dropped, and an empty vec is returned when nothing new exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let new_points = client.get_price_history_since("012345678901", "2024-03-01", None).await?;
+    /// for offer in new_points {
+    ///     store.append(&offer.id, &offer.price_history)?;
+    /// }
+    /// ```
+    pub async fn get_price_history_since(&self, identifier: impl Into<ProductIdentifier>, last_synced_date: &str, retailer: Option<&str>) -> Result<Vec<OfferWithHistory>> {
+        let last_synced = dates::parse_date(last_synced_date)
+            .ok_or_else(|| Error::validation(format!("Invalid date {:?}, expected YYYY-MM-DD", last_synced_date)))?;
+        let today = dates::today();
+        if last_synced >= today {
+            return Ok(Vec::new());
+        }
+
+        let start_date = dates::format_date(last_synced + 1);
+        let end_date = dates::format_date(today);
+        let history = self.get_price_history(identifier, &start_date, &end_date, retailer, None, None).await?;
+
+        let mut offers = history.data;
+        for offer in &mut offers {
+            offer.price_history.retain(|entry| {
+                dates::parse_date(entry.date.get(..10).unwrap_or(&entry.date)).is_some_and(|day| day > last_synced)
+            });
+        }
+        offers.retain(|offer| !offer.price_history.is_empty());
+        Ok(offers)
+    }
+
+    /// Get the price at each retailer on a single day
+    ///
+    /// Fetches a one-day history range and returns `(retailer, price)` pairs for
+    /// offers with an entry on that date. Returns `Error::NotFound` when no
+    /// retailer has data for the day.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let prices = client.get_price_on_date("012345678901", "2024-11-29", None).await?;
+    /// for (retailer, price) in prices {
+    ///     println!("{}: ${:.2}", retailer, price);
+    /// }
+    /// ```
+    pub async fn get_price_on_date(&self, identifier: impl Into<ProductIdentifier>, date: &str, retailer: Option<&str>) -> Result<Vec<(String, f64)>> {
+        let identifier: ProductIdentifier = identifier.into();
+        if dates::parse_date(date).is_none() {
+            return Err(Error::validation(format!("Invalid date {:?}, expected YYYY-MM-DD", date)));
+        }
+
+        let history = self.get_price_history(&identifier, date, date, retailer, None, None).await?;
+        let prices: Vec<(String, f64)> = history
+            .data
+            .into_iter()
+            .filter_map(|offer| {
+                let entry = offer.price_history.iter().find(|entry| entry.date.starts_with(date))?;
+                Some((offer.retailer.unwrap_or_default(), entry.price))
+            })
+            .collect();
+
+        if prices.is_empty() {
+            return Err(Error::not_found(format!("No price data for {} on {}", identifier, date)));
+        }
+        Ok(prices)
+    }
+
+    /// Get current offers together with their recent price history
+    ///
+    /// Fetches current offers and the last `days` days of history, then attaches
+    /// each offer's history by offer id (falling back to the retailer name).
+    /// Offers without any history get an empty `price_history`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let offers = client.get_offers_with_history("012345678901", 30, None).await?;
+    /// for offer in offers {
+    ///     println!("{:?}: {} history points", offer.retailer, offer.price_history.len());
+    /// }
+    /// ```
+    pub async fn get_offers_with_history(&self, identifier: impl Into<ProductIdentifier>, days: u32, retailer: Option<&str>) -> Result<Vec<OfferWithHistory>> {
+        let identifier: ProductIdentifier = identifier.into();
+        let today = dates::today();
+        let start_date = dates::format_date(today - i64::from(days));
+        let end_date = dates::format_date(today);
+
+        let (offers, history) = tokio::try_join!(
+            self.get_current_offers(&identifier, retailer, None, None),
+            self.get_price_history(&identifier, &start_date, &end_date, retailer, None, None),
+        )?;
+
+        let mut history = history.data;
+        let merged = offers
+            .data
+            .into_iter()
+            .flat_map(|product| product.offers)
+            .map(|offer| {
+                let position = history.iter().position(|h| h.id == offer.id).or_else(|| {
+                    let slug = offer.retailer.as_deref().map(normalize_retailer)?;
+                    history
+                        .iter()
+                        .position(|h| h.retailer.as_deref().map(normalize_retailer).as_ref() == Some(&slug))
+                });
+                let price_history = position
+                    .map(|index| history.swap_remove(index).price_history)
+                    .unwrap_or_default();
+
+                OfferWithHistory {
+                    id: offer.id,
+                    retailer: offer.retailer,
+                    price: offer.price,
+                    currency: offer.currency,
+                    availability: offer.availability,
+                    condition: offer.condition,
+                    url: offer.url,
+                    seller: offer.seller,
+                    timestamp: offer.timestamp,
+                    price_history,
+                }
+            })
+            .collect();
+
+        Ok(merged)
+    }
+
+    /// Fetch price history and write it to CSV
+    ///
+    /// Each history entry becomes one row with retailer and offer id columns.
+    /// The writer is flushed before returning.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let file = std::fs::File::create("history.csv")?;
+    /// client.export_price_history_csv("012345678901", "2024-01-01", "2024-01-31", file).await?;
+    /// ```
+    #[cfg(feature = "csv")]
+    pub async fn export_price_history_csv<W: std::io::Write>(&self, identifier: impl Into<ProductIdentifier>, start_date: &str, end_date: &str, writer: W) -> Result<()> {
+        let history = self.get_price_history(identifier, start_date, end_date, None, None, None).await?;
+        crate::export::write_price_history_csv(&history.data, writer)
+    }
+
+    /// Compare the average price of a product across two date ranges
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - Product identifier
+    /// * `range_a` - First (start, end) date range (YYYY-MM-DD format)
+    /// * `range_b` - Second (start, end) date range (YYYY-MM-DD format)
+    /// * `retailer` - Optional retailer to filter by
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let comparison = client.compare_price_periods(
+    ///     "012345678901",
+    ///     ("2024-01-01", "2024-01-31"),
+    ///     ("2024-02-01", "2024-02-29"),
+    ///     None
+    /// ).await?;
+    /// println!("Change: {:?}%", comparison.pct_change);
+    /// ```
+    pub async fn compare_price_periods(&self, identifier: impl Into<ProductIdentifier>, range_a: (&str, &str), range_b: (&str, &str), retailer: Option<&str>) -> Result<PriceComparison> {
+        let identifier: ProductIdentifier = identifier.into();
+        let (history_a, history_b) = tokio::try_join!(
+            self.get_price_history(&identifier, range_a.0, range_a.1, retailer, None, None),
+            self.get_price_history(&identifier, range_b.0, range_b.1, retailer, None, None),
+        )?;
+
+        Ok(PriceComparison::new(
+            average_history_price(&history_a.data),
+            average_history_price(&history_b.data),
+        ))
     }
 
     /// Schedule product monitoring
@@ -343,7 +1547,8 @@ impl Client {
     ///     None
     /// ).await?;
     /// ```
-    pub async fn schedule_product_monitoring(&self, identifier: &str, frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleResponse>> {
+    pub async fn schedule_product_monitoring(&self, identifier: impl Into<ProductIdentifier>, frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleResponse>> {
+        let identifier = &*self.request_identifier(identifier)?;
         let mut body = serde_json::json!({
             "identifier": identifier,
             "frequency": frequency.to_string(),
@@ -356,9 +1561,61 @@ impl Client {
         self.request(reqwest::Method::POST, "/products/schedule", None, Some(&body)).await
     }
 
+    /// Schedule product monitoring and wait until the first refresh completes
+    ///
+    /// Polls the scheduled products every `poll_interval` until the product
+    /// reports a `last_refreshed` time, failing with `Error::Timeout` once
+    /// `timeout` has elapsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let scheduled = client.schedule_and_await_refresh(
+    ///     "012345678901",
+    ///     MonitoringFrequency::Daily,
+    ///     Duration::from_secs(10),
+    ///     Duration::from_secs(300)
+    /// ).await?;
+    /// ```
+    pub async fn schedule_and_await_refresh(&self, identifier: impl Into<ProductIdentifier>, frequency: MonitoringFrequency, poll_interval: Duration, timeout: Duration) -> Result<ScheduledProduct> {
+        // Compare against what was sent, since the server echoes the normalized form
+        let identifier = self.request_identifier(identifier)?;
+        let started = Instant::now();
+        let scheduled = self.schedule_product_monitoring(&identifier, frequency, None).await?;
+        let product_id = scheduled.data.product_id;
+
+        loop {
+            let products = self.get_scheduled_products().await?;
+            let refreshed = products
+                .data
+                .into_iter()
+                .find(|p| (p.product_id == product_id || p.identifier == identifier) && p.last_refreshed.is_some());
+            if let Some(product) = refreshed {
+                return Ok(product);
+            }
+
+            if started.elapsed() + poll_interval > timeout {
+                return Err(Error::Timeout);
+            }
+            crate::time::sleep(poll_interval).await;
+        }
+    }
+
     /// Schedule monitoring for multiple products
-    pub async fn schedule_product_monitoring_batch(&self, identifiers: &[&str], frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<Vec<ScheduleBatchResponse>>> {
-        let identifiers_str = identifiers.join(",");
+    ///
+    /// The batch can partially succeed, so outcomes are reported per identifier
+    /// rather than as a single error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let results = client.schedule_product_monitoring_batch(&ids, MonitoringFrequency::Daily, None).await?;
+    /// for failure in results.data.failed() {
+    ///     eprintln!("{}: {}", failure.identifier, failure.error.as_deref().unwrap_or("not scheduled"));
+    /// }
+    /// ```
+    pub async fn schedule_product_monitoring_batch(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>, frequency: MonitoringFrequency, retailer: Option<&str>) -> Result<ApiResponse<ScheduleBatchResults>> {
+        let identifiers_str = self.join_batch(identifiers)?;
         let mut body = serde_json::json!({
             "identifiers": identifiers_str,
             "frequency": frequency.to_string(),
@@ -384,7 +1641,15 @@ impl Client {
     }
 
     /// Remove product from monitoring schedule
-    pub async fn remove_product_from_schedule(&self, identifier: &str) -> Result<ApiResponse<RemoveResponse>> {
+    ///
+    /// See [`Config::with_delete_via_query`] for gateways that drop DELETE bodies.
+    pub async fn remove_product_from_schedule(&self, identifier: impl Into<ProductIdentifier>) -> Result<ApiResponse<RemoveResponse>> {
+        let identifier = &*self.request_identifier(identifier)?;
+        if self.inner.config.delete_via_query {
+            let params = [("identifier", identifier)];
+            return self.request(reqwest::Method::DELETE, "/products/schedule", Some(&params), None).await;
+        }
+
         let body = serde_json::json!({
             "identifier": identifier,
         });
@@ -393,8 +1658,27 @@ impl Client {
     }
 
     /// Remove multiple products from monitoring schedule
-    pub async fn remove_products_from_schedule(&self, identifiers: &[&str]) -> Result<ApiResponse<Vec<RemoveBatchResponse>>> {
-        let identifiers_str = identifiers.join(",");
+    ///
+    /// Identifiers that are not currently scheduled do not fail the batch; they
+    /// are reported per identifier alongside the removed ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let results = client.remove_products_from_schedule(&ids).await?;
+    /// println!(
+    ///     "{} removed, {} not currently scheduled",
+    ///     results.data.removed().count(),
+    ///     results.data.not_scheduled().count()
+    /// );
+    /// ```
+    pub async fn remove_products_from_schedule(&self, identifiers: impl IntoIterator<Item = impl Into<ProductIdentifier>>) -> Result<ApiResponse<RemoveBatchResults>> {
+        let identifiers_str = self.join_batch(identifiers)?;
+        if self.inner.config.delete_via_query {
+            let params = [("identifiers", identifiers_str.as_str())];
+            return self.request(reqwest::Method::DELETE, "/products/schedule", Some(&params), None).await;
+        }
+
         let body = serde_json::json!({
             "identifiers": identifiers_str,
         });
@@ -413,4 +1697,683 @@ impl Client {
     pub async fn get_usage(&self) -> Result<ApiResponse<UsageInfo>> {
         self.request(reqwest::Method::GET, "/usage", None, None).await
     }
+
+    /// Check whether the API key is accepted by the server
+    ///
+    /// Makes a cheap authenticated `/usage` call. A rejected key (401) returns
+    /// `Ok(false)`; other failures such as network errors are returned as errors.
+    /// Key format is already checked when the client is built.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if !client.validate_key().await? {
+    ///     eprintln!("This API key has been revoked or is invalid");
+    /// }
+    /// ```
+    pub async fn validate_key(&self) -> Result<bool> {
+        match self.get_usage().await {
+            Ok(_) => Ok(true),
+            Err(Error::Authentication { .. }) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Fetch usage and classify the remaining credits
+    ///
+    /// Thresholds are percentages of credits remaining; see [`UsageInfo::alert`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match client.check_usage_threshold(10.0, 2.0).await? {
+    ///     UsageAlert::Ok => {}
+    ///     UsageAlert::Warning => log::warn!("Less than 10% of credits left"),
+    ///     UsageAlert::Critical => log::error!("Less than 2% of credits left"),
+    /// }
+    /// ```
+    pub async fn check_usage_threshold(&self, warning_pct: f64, critical_pct: f64) -> Result<UsageAlert> {
+        let usage = self.get_usage().await?;
+        Ok(usage.data.alert(warning_pct, critical_pct))
+    }
+}
+
+/// Generate [`ClientBuilder`] setters that forward to the [`ConfigBuilder`] setter of the same name
+macro_rules! forward_to_config {
+    ($($(#[$attr:meta])* fn $name:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("Forwards to [`ConfigBuilder::", stringify!($name), "`]")]
+            pub fn $name(mut self, $($arg: $ty),*) -> Self {
+                self.config = self.config.$name($($arg),*);
+                self
+            }
+        )*
+    };
+}
+
+/// Builder for [`Client`] exposing every [`ConfigBuilder`] option, created by [`Client::builder`]
+///
+/// Without an explicit timeout, `ss_test_` keys get a 60 second timeout since
+/// the sandbox responds more slowly; live keys keep the 30 second default.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use shopsavvy_sdk::Client;
+///
+/// let client = Client::builder()
+///     .api_key("ss_live_your_api_key_here")
+///     .max_retries(3)
+///     .rate_limit(5.0)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    config: ConfigBuilder,
+}
+
+impl ClientBuilder {
+    forward_to_config! {
+        fn api_key(api_key: impl Into<String>);
+        fn base_url(base_url: impl Into<String>);
+        fn timeout(timeout: Duration);
+        fn connect_timeout(timeout: Duration);
+        fn header(name: impl Into<String>, value: impl Into<String>);
+        fn headers(headers: HeaderMap);
+        fn user_agent(user_agent: impl Into<String>);
+        fn language(language: impl Into<String>);
+        fn retry(retry: crate::retry::RetryConfig);
+        fn max_retries(max_retries: u32);
+        fn rate_limit(requests_per_second: f64);
+        fn circuit_breaker(failure_threshold: u32, window: Duration, cooldown: Duration);
+        fn pool_max_idle_per_host(max_idle: usize);
+        fn pool_idle_timeout(timeout: Duration);
+        fn http2_prior_knowledge(enabled: bool);
+        fn danger_accept_invalid_certs(accept: bool);
+        fn request_observer(observer: impl Fn(&RequestInfo) + Send + Sync + 'static);
+        fn request_id_generator(generator: impl Fn() -> String + Send + Sync + 'static);
+        fn dry_run(dry_run: bool);
+        fn delete_via_query(enabled: bool);
+        fn max_response_bytes(max_bytes: usize);
+        fn max_batch_size(max_batch_size: usize);
+        fn fan_out_concurrency(concurrency: usize);
+        fn identifier_normalization(enabled: bool);
+        #[cfg(feature = "replay")]
+        fn cassette(cassette: crate::replay::Cassette);
+        #[cfg(feature = "cache")]
+        fn cache_capacity(capacity: usize);
+        #[cfg(feature = "cache")]
+        fn cache_ttl(ttl: Duration);
+    }
+
+    /// Use these options, replacing any set before
+    pub fn config(mut self, config: ConfigBuilder) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Validate the options and the API key and build the client
+    ///
+    /// Fails with the same errors as [`Client::with_config`].
+    pub fn build(self) -> Result<Client> {
+        let timeout_set = self.config.timeout_set;
+        let mut config = self.config.build()?;
+        if !timeout_set && config.api_key.starts_with("ss_test_") {
+            config.timeout = TEST_KEY_TIMEOUT;
+        }
+        Client::with_config(config)
+    }
+}
+
+/// Run a request until it completes or `cancel` resolves
+///
+/// `cancel` is polled first, so a request whose signal has already fired is
+/// never sent. Works with any future, e.g. `CancellationToken::cancelled()`
+/// from `tokio-util` or a `oneshot` receiver.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let token = CancellationToken::new();
+/// let search = client.search_products("usb c cable", None, None);
+/// match shopsavvy_sdk::cancellable(search, token.cancelled()).await {
+///     Err(Error::Cancelled) => println!("search abandoned"),
+///     result => println!("{} results", result?.len()),
+/// }
+/// ```
+pub async fn cancellable<T>(request: impl Future<Output = Result<T>>, cancel: impl Future<Output = ()>) -> Result<T> {
+    tokio::select! {
+        biased;
+        _ = cancel => Err(Error::Cancelled),
+        result = request => result,
+    }
+}
+
+/// Top-level `meta` object shared by all JSON responses
+#[derive(Deserialize)]
+struct MetaEnvelope {
+    meta: Option<ApiMeta>,
+}
+
+/// Outcome of a successful request, before its body is read
+enum Fetched {
+    /// 304 response answered from the stored ETag body
+    #[cfg_attr(not(feature = "cache"), allow(dead_code))]
+    NotModified(String, HeaderMap),
+    Response(reqwest::Response),
+}
+
+/// Top-level status fields shared by all JSON responses
+#[derive(Deserialize)]
+struct StatusEnvelope {
+    success: Option<bool>,
+    message: Option<String>,
+    error: Option<String>,
+}
+
+/// Get the error message of a response reporting `success: false`
+fn soft_failure(response_text: &str) -> Option<String> {
+    let envelope = serde_json::from_str::<StatusEnvelope>(response_text).ok()?;
+    failure_message(envelope.success, envelope.message, envelope.error)
+}
+
+/// Pick the error message of a response whose top-level `success` is false
+fn failure_message(success: Option<bool>, message: Option<String>, error: Option<String>) -> Option<String> {
+    if success != Some(false) {
+        return None;
+    }
+    Some(
+        message
+            .or(error)
+            .unwrap_or_else(|| "The API reported the request as unsuccessful".to_string()),
+    )
+}
+
+/// Deserialize a response body, reporting the JSON path of any mismatch
+///
+/// The path to the offending field (e.g. `data[0].offers[2]`) is appended to
+/// the `Error::Json` message, so changes in the response shape can be traced.
+fn parse_json<T>(text: &str) -> Result<T>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(json_path_error)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Convert a deserialization error, appending the JSON path unless it is the root
+pub(crate) fn json_path_error(err: serde_path_to_error::Error<serde_json::Error>) -> Error {
+    let path = err.path().to_string();
+    let inner = err.into_inner();
+    if path == "." {
+        Error::Json(inner)
+    } else {
+        Error::Json(serde::de::Error::custom(format!("{} at {}", inner, path)))
+    }
+}
+
+/// Append an endpoint to the base URL with exactly one slash between them
+///
+/// Keeps any path prefix on the base URL, e.g. `https://gateway/external/v1/`
+/// and `/products` become `https://gateway/external/v1/products`.
+fn join_url(base_url: &str, endpoint: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), endpoint.trim_start_matches('/'))
+}
+
+/// Turn a 404 into `Ok(None)`, passing through other results
+fn not_found_as_none<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotFound { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Validate a batch of identifiers and join them for the `ids` parameter
+fn join_identifiers<S: AsRef<str>>(identifiers: &[S]) -> Result<String> {
+    if identifiers.is_empty() {
+        return Err(Error::validation("At least one identifier is required"));
+    }
+    if let Some(position) = identifiers.iter().position(|id| id.as_ref().trim().is_empty()) {
+        return Err(Error::validation(format!("Identifier at index {} is empty", position)));
+    }
+    let identifiers: Vec<&str> = identifiers.iter().map(AsRef::as_ref).collect();
+    for identifier in &identifiers {
+        validate_identifier(identifier)?;
+    }
+    Ok(identifiers.join(","))
+}
+
+/// Validate an ISO 3166-1 alpha-2 country code and return it uppercased
+fn country_code(code: &str) -> Result<String> {
+    if code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(code.to_ascii_uppercase())
+    } else {
+        Err(Error::validation(format!(
+            "Invalid country code '{}': expected an ISO 3166-1 alpha-2 code such as US or GB",
+            code
+        )))
+    }
+}
+
+/// Check whether a transport error is worth retrying
+pub(crate) fn is_transient(err: &reqwest::Error) -> bool {
+    #[cfg(not(feature = "wasm"))]
+    if err.is_connect() {
+        return true;
+    }
+    err.is_timeout()
+}
+
+/// Parse a `Retry-After` header given in seconds
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers.get(RETRY_AFTER)?.to_str().ok()?.trim().parse::<u64>().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retry::RetryConfig;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Format a raw HTTP/1.1 response for [`serve`]
+    fn http_response(status: u16, headers: &[(&str, &str)], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\n", status);
+        for (name, value) in headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str(&format!("content-length: {}\r\nconnection: close\r\n\r\n{}", body.len(), body));
+        response
+    }
+
+    /// Serve canned responses on a local port, one per connection, repeating the last
+    ///
+    /// Returns the base URL to configure the client with.
+    async fn serve(responses: Vec<String>) -> String {
+        serve_counting(responses).await.0
+    }
+
+    /// Like [`serve`], also returning how many connections were accepted
+    async fn serve_counting(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let served = accepted.fetch_add(1, Ordering::SeqCst);
+                let response = responses[served.min(responses.len() - 1)].clone();
+                tokio::spawn(async move {
+                    // Read up to the end of the request headers; test requests have small bodies
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 4096];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+        (format!("http://{}/v1", addr), connections)
+    }
+
+    fn test_client(base_url: &str) -> Client {
+        Client::with_config(Config::new("ss_test_abc123").with_base_url(base_url)).unwrap()
+    }
+
+    #[test]
+    fn client_is_send_sync_clone() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone + 'static>() {}
+        assert_send_sync_clone::<Client>();
+    }
+
+    #[tokio::test]
+    async fn clones_share_state_across_tasks() {
+        let body = r#"{"success":true,"data":{"current_period":{"start_date":"2024-01-01","end_date":"2024-01-31","credits_used":5,"credits_limit":100,"credits_remaining":95,"requests_made":5},"usage_percentage":5.0},"meta":{"credits_used":1,"credits_remaining":95}}"#;
+        let base_url = serve(vec![http_response(200, &[], body)]).await;
+        let client = test_client(&base_url);
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.get_usage().await.map(|usage| usage.data.usage_percentage) })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap(), 5.0);
+        }
+
+        // Usage recorded through the clones is visible on the original handle
+        assert_eq!(client.last_credits_remaining(), Some(95));
+    }
+
+    #[tokio::test]
+    async fn retry_after_past_deadline_times_out_at_once() {
+        let base_url = serve(vec![http_response(503, &[("retry-after", "60")], r#"{"error":"Unavailable"}"#)]).await;
+        let client = Client::with_config(
+            Config::new("ss_test_abc123")
+                .with_base_url(base_url)
+                .with_retry(RetryConfig::new(3)),
+        )
+        .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let result = tokio::time::timeout(Duration::from_secs(5), client.with_deadline(deadline).get_usage())
+            .await
+            .expect("retry slept past the deadline");
+        assert!(matches!(result, Err(Error::Timeout)), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_capped_at_max_delay() {
+        let base_url = serve(vec![http_response(429, &[("retry-after", "60")], r#"{"error":"Slow down"}"#)]).await;
+        let client = Client::with_config(
+            Config::new("ss_test_abc123")
+                .with_base_url(base_url)
+                .with_retry(
+                    RetryConfig::new(1)
+                        .with_base_delay(Duration::from_millis(10))
+                        .with_max_delay(Duration::from_millis(50)),
+                ),
+        )
+        .unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), client.get_usage())
+            .await
+            .expect("Retry-After was not capped at max_delay");
+        assert!(matches!(result, Err(Error::RateLimit { .. })), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_body_meta_updates_credits() {
+        let body = r#"{"success":false,"error":"Out of credits","meta":{"credits_used":0,"credits_remaining":0}}"#;
+        let base_url = serve(vec![http_response(429, &[], body)]).await;
+        let client = Client::with_config(
+            Config::new("ss_test_abc123")
+                .with_base_url(base_url)
+                .with_retry(RetryConfig::new(3).with_base_delay(Duration::from_millis(1))),
+        )
+        .unwrap();
+
+        let err = client.get_usage().await.unwrap_err();
+        assert_eq!(client.last_credits_remaining(), Some(0));
+        assert!(matches!(err, Error::CreditsExhausted { status_code: 429, .. }), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn raw_identifiers_are_validated_before_sending() {
+        // Nothing listens here, so reaching the network would fail differently
+        let client = test_client("http://127.0.0.1:9/v1");
+
+        let err = client.get_product_details("  ", None, None).await.unwrap_err();
+        assert!(matches!(err, Error::Validation { .. }), "{:?}", err);
+
+        let err = client.get_current_offers("https://exa mple.com/p", None, None, None).await.unwrap_err();
+        assert!(matches!(err, Error::Validation { .. }), "{:?}", err);
+
+        let err = client.get_product_details_batch(["012345678901", "model 42"], None).await.unwrap_err();
+        assert!(matches!(err, Error::Validation { .. }), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn await_refresh_matches_the_normalized_identifier() {
+        // The scheduled list reports a different product ID, so only the identifier can match
+        let scheduled = r#"{"success":true,"data":{"scheduled":true,"product_id":"p1"}}"#;
+        let listed = r#"{"success":true,"data":[{"product_id":"p2","identifier":"B08N5WRWNW","frequency":"daily","created_at":"2024-01-01T00:00:00Z","last_refreshed":"2024-01-01T00:05:00Z"}]}"#;
+        let base_url = serve(vec![http_response(200, &[], scheduled), http_response(200, &[], listed)]).await;
+        let client = test_client(&base_url);
+
+        let product = client
+            .schedule_and_await_refresh(" b08n5wrwnw\n", MonitoringFrequency::Daily, Duration::from_millis(1), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(product.product_id, "p2");
+    }
+
+    #[test]
+    fn builder_forwards_config_options() {
+        let client = Client::builder()
+            .api_key("ss_test_abc123")
+            .max_batch_size(7)
+            .max_retries(2)
+            .identifier_normalization(false)
+            .build()
+            .unwrap();
+        assert_eq!(client.inner.config.max_batch_size, 7);
+        assert_eq!(client.inner.config.retry.as_ref().map(|retry| retry.max_retries), Some(2));
+        assert!(!client.inner.config.normalize_identifiers);
+    }
+
+    #[test]
+    fn builder_gives_test_keys_a_longer_timeout_unless_set() {
+        let client = Client::builder().api_key("ss_test_abc123").build().unwrap();
+        assert_eq!(client.inner.config.timeout, TEST_KEY_TIMEOUT);
+
+        let client = Client::builder()
+            .api_key("ss_test_abc123")
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        assert_eq!(client.inner.config.timeout, Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn cancelled_before_send_makes_no_request() {
+        let body = r#"{"success":true,"data":{}}"#;
+        let (base_url, connections) = serve_counting(vec![http_response(200, &[], body)]).await;
+        let client = test_client(&base_url);
+
+        let result = cancellable(client.get_usage(), std::future::ready(())).await;
+        assert!(matches!(result, Err(Error::Cancelled)), "{:?}", result);
+
+        // Give a stray request time to arrive before checking that none did
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(connections.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn cancelled_mid_flight_drops_the_request() {
+        // Accept the request but never answer it, reporting when the client hangs up
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let (received_tx, received_rx) = tokio::sync::oneshot::channel();
+        let (closed_tx, closed_rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let _ = received_tx.send(());
+            while let Ok(n) = socket.read(&mut buf).await {
+                if n == 0 {
+                    break;
+                }
+            }
+            let _ = closed_tx.send(());
+        });
+        let client = test_client(&base_url);
+
+        let cancel = async {
+            let _ = received_rx.await;
+        };
+        let result = cancellable(client.get_usage(), cancel).await;
+        assert!(matches!(result, Err(Error::Cancelled)), "{:?}", result);
+
+        tokio::time::timeout(Duration::from_secs(5), closed_rx)
+            .await
+            .expect("connection was kept open after cancelling")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn resolve_product_ids_matches_dashed_barcodes() {
+        let body = r#"{"success":true,"data":[{"title":"Widget","shopsavvy":"ss-1","barcode":"012345678905"}],"meta":{"credits_used":1,"credits_remaining":95}}"#;
+        let base_url = serve(vec![http_response(200, &[], body)]).await;
+        let client = test_client(&base_url);
+
+        let resolved = client.resolve_product_ids(["0-12345-67890-5"]).await.unwrap();
+        assert_eq!(resolved.get("0-12345-67890-5").map(String::as_str), Some("ss-1"));
+    }
+
+    #[tokio::test]
+    async fn resolve_product_ids_matches_upc_against_ean13() {
+        let body = r#"{"success":true,"data":[{"title":"Widget","shopsavvy":"ss-1","barcode":"0012345678905"}],"meta":{"credits_used":1,"credits_remaining":95}}"#;
+        let base_url = serve(vec![http_response(200, &[], body)]).await;
+        let client = test_client(&base_url);
+
+        let resolved = client.resolve_product_ids(["012345678905"]).await.unwrap();
+        assert_eq!(resolved.get("012345678905").map(String::as_str), Some("ss-1"));
+    }
+
+    #[tokio::test]
+    async fn resolve_product_ids_looks_up_urls_individually() {
+        let batch = r#"{"success":true,"data":[{"title":"Widget","shopsavvy":"ss-1","amazon":"B08N5WRWNW"}],"meta":{"credits_used":1,"credits_remaining":95}}"#;
+        let single = r#"{"success":true,"data":[{"title":"Gadget","shopsavvy":"ss-2"}],"meta":{"credits_used":1,"credits_remaining":94}}"#;
+        let (base_url, connections) =
+            serve_counting(vec![http_response(200, &[], batch), http_response(200, &[], single)]).await;
+        let client = test_client(&base_url);
+
+        let url = "https://www.example.com/products/gadget";
+        let resolved = client.resolve_product_ids(["B08N5WRWNW", url]).await.unwrap();
+        assert_eq!(resolved.get("B08N5WRWNW").map(String::as_str), Some("ss-1"));
+        assert_eq!(resolved.get(url).map(String::as_str), Some("ss-2"));
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn product_details_map_matches_normalized_barcodes() {
+        let body = r#"{"success":true,"data":[{"title":"Widget","shopsavvy":"ss-1","barcode":"012345678905"},{"title":"Gadget","shopsavvy":"ss-2","barcode":"0012345678912"}],"meta":{"credits_used":2,"credits_remaining":95}}"#;
+        let base_url = serve(vec![http_response(200, &[], body)]).await;
+        let client = test_client(&base_url);
+
+        let products = client.get_product_details_map(["0-12345-67890-5", "012345678912", "B08N5WRWNW"]).await.unwrap();
+        assert_eq!(products.len(), 3);
+        assert_eq!(products["0-12345-67890-5"].as_ref().map(|p| p.shopsavvy.as_str()), Some("ss-1"));
+        assert_eq!(products["012345678912"].as_ref().map(|p| p.shopsavvy.as_str()), Some("ss-2"));
+        assert!(products["B08N5WRWNW"].is_none());
+    }
+
+    #[tokio::test]
+    async fn price_history_batch_caps_concurrency_and_fetches_duplicates_once() {
+        // Hold each response briefly and record the most connections open at once
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let (in_flight_server, peak_server, requests_server) = (in_flight.clone(), peak.clone(), requests.clone());
+        tokio::spawn(async move {
+            let response = http_response(200, &[], r#"{"success":true,"data":[],"meta":{"credits_used":1,"credits_remaining":95}}"#);
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let (in_flight, peak, response) = (in_flight_server.clone(), peak_server.clone(), response.clone());
+                requests_server.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let open = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(open, Ordering::SeqCst);
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        let client = Client::with_config(
+            Config::new("ss_test_abc123")
+                .with_base_url(base_url)
+                .with_fan_out_concurrency(2),
+        )
+        .unwrap();
+
+        let mut identifiers: Vec<String> = (1..=6).map(|i| format!("B00000000{}", i)).collect();
+        identifiers.push("b000000001".to_string());
+        let history = client
+            .get_price_history_batch(&identifiers, "2024-01-01", "2024-01-31", None)
+            .await
+            .unwrap();
+
+        assert_eq!(history.len(), 7);
+        assert_eq!(requests.load(Ordering::SeqCst), 6);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn price_history_batch_enforces_max_batch_size() {
+        let (base_url, connections) = serve_counting(vec![http_response(200, &[], "{}")]).await;
+        let client = Client::with_config(
+            Config::new("ss_test_abc123")
+                .with_base_url(base_url)
+                .with_max_batch_size(2),
+        )
+        .unwrap();
+
+        let result = client
+            .get_price_history_batch(["B000000001", "B000000002", "B000000003"], "2024-01-01", "2024-01-31", None)
+            .await;
+        assert!(matches!(result, Err(Error::Validation { .. })), "{:?}", result);
+        assert_eq!(connections.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn soft_failure_reads_message_then_error() {
+        assert_eq!(soft_failure(r#"{"success":false,"message":"Suspended"}"#).as_deref(), Some("Suspended"));
+        assert_eq!(soft_failure(r#"{"success":false,"error":"Bad identifier"}"#).as_deref(), Some("Bad identifier"));
+        assert_eq!(soft_failure(r#"{"success":true,"data":[]}"#), None);
+        assert_eq!(soft_failure(r#"{"data":[]}"#), None);
+    }
+
+    #[tokio::test]
+    async fn success_false_with_message_is_api_error() {
+        let base_url = serve(vec![http_response(200, &[], r#"{"success":false,"message":"Account suspended"}"#)]).await;
+        let err = test_client(&base_url).get_usage().await.unwrap_err();
+        assert!(
+            matches!(&err, Error::Api { message, status_code: 200 } if message == "Account suspended"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn success_false_with_error_is_api_error() {
+        let base_url = serve(vec![http_response(200, &[], r#"{"success":false,"error":"Unknown identifier"}"#)]).await;
+        let err = test_client(&base_url).get_usage().await.unwrap_err();
+        assert!(
+            matches!(&err, Error::Api { message, status_code: 200 } if message == "Unknown identifier"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn join_url_base_without_trailing_slash() {
+        assert_eq!(join_url("https://api.shopsavvy.com/v1", "products"), "https://api.shopsavvy.com/v1/products");
+    }
+
+    #[test]
+    fn join_url_base_with_trailing_slash() {
+        assert_eq!(join_url("https://api.shopsavvy.com/v1/", "products"), "https://api.shopsavvy.com/v1/products");
+    }
+
+    #[test]
+    fn join_url_keeps_path_prefix() {
+        assert_eq!(join_url("https://proxy/x/v1", "products/offers"), "https://proxy/x/v1/products/offers");
+        assert_eq!(join_url("https://proxy/x/v1/", "products/offers"), "https://proxy/x/v1/products/offers");
+    }
+
+    #[test]
+    fn join_url_endpoint_with_leading_slash() {
+        assert_eq!(join_url("https://api.shopsavvy.com/v1", "/usage"), "https://api.shopsavvy.com/v1/usage");
+        assert_eq!(join_url("https://api.shopsavvy.com/v1/", "/usage"), "https://api.shopsavvy.com/v1/usage");
+        assert_eq!(join_url("https://proxy/x/v1", "/products"), "https://proxy/x/v1/products");
+    }
 }
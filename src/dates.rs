@@ -0,0 +1,103 @@
+//! Minimal calendar-date arithmetic on `YYYY-MM-DD` strings
+
+use crate::time::{SystemTime, UNIX_EPOCH};
+
+/// Convert days since 1970-01-01 to a (year, month, day) civil date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) civil date to days since 1970-01-01
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parse a `YYYY-MM-DD` date into days since 1970-01-01
+pub(crate) fn parse_date(date: &str) -> Option<i64> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year: i64 = date[0..4].parse().ok()?;
+    let month: u32 = date[5..7].parse().ok()?;
+    let day: u32 = date[8..10].parse().ok()?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Format days since 1970-01-01 as `YYYY-MM-DD`
+pub(crate) fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Today's date (UTC) as days since 1970-01-01
+pub(crate) fn today() -> i64 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    (seconds / 86_400) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_dates() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2024-01-01"), Some(19_723));
+        assert_eq!(parse_date("1969-12-31"), Some(-1));
+    }
+
+    #[test]
+    fn civil_conversion_round_trips() {
+        for days in (-25_000..50_000).step_by(7) {
+            assert_eq!(parse_date(&format_date(days)), Some(days), "day {}", days);
+        }
+        assert_eq!(format_date(19_723), "2024-01-01");
+    }
+
+    #[test]
+    fn leap_days_follow_the_gregorian_rules() {
+        assert!(parse_date("2024-02-29").is_some());
+        assert!(parse_date("2000-02-29").is_some());
+        assert!(parse_date("2023-02-29").is_none());
+        assert!(parse_date("1900-02-29").is_none());
+        assert_eq!(parse_date("2024-03-01"), parse_date("2024-02-29").map(|day| day + 1));
+    }
+
+    #[test]
+    fn rejects_malformed_dates() {
+        for date in ["2024-13-01", "2024-00-10", "2024-04-31", "2024-01-00", "2024-1-01", "2024/01/01", "20240101", ""] {
+            assert_eq!(parse_date(date), None, "{:?}", date);
+        }
+    }
+}
@@ -18,6 +18,12 @@ pub enum Error {
     #[error("Rate limit exceeded: {message}")]
     RateLimit { message: String, status_code: u16 },
 
+    #[error("Payment required: {message}")]
+    PaymentRequired { message: String, status_code: u16 },
+
+    #[error("Credits exhausted: {message}")]
+    CreditsExhausted { message: String, status_code: u16 },
+
     #[error("API error ({status_code}): {message}")]
     Api { message: String, status_code: u16 },
 
@@ -27,6 +33,9 @@ pub enum Error {
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Invalid API key format. API keys should start with ss_live_ or ss_test_")]
     InvalidApiKey,
 
@@ -35,15 +44,84 @@ pub enum Error {
 
     #[error("Request timeout")]
     Timeout,
+
+    #[error("Request cancelled")]
+    Cancelled,
+
+    #[error("Response body exceeded the {limit} byte limit")]
+    ResponseTooLarge { limit: usize },
+
+    #[error("Circuit breaker is open after repeated failures; not sending request")]
+    CircuitOpen,
+
+    #[error("Dry run: request to {url} was not sent")]
+    DryRun { url: String, body: Option<String> },
 }
 
 impl Error {
+    /// Validation error raised locally before any request is sent
+    ///
+    /// Local validation errors carry a `status_code` of 0.
+    pub(crate) fn validation(message: impl Into<String>) -> Self {
+        Error::Validation {
+            message: message.into(),
+            status_code: 0,
+        }
+    }
+
+    /// Not-found error raised locally when a successful response has no match
+    ///
+    /// Like local validation errors, these carry a `status_code` of 0.
+    pub(crate) fn not_found(message: impl Into<String>) -> Self {
+        Error::NotFound {
+            message: message.into(),
+            status_code: 0,
+        }
+    }
+
+    /// Get the HTTP status code associated with this error, if any
+    ///
+    /// Errors raised before a response was received (including local
+    /// validation errors) return `None`.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Error::Authentication { status_code, .. }
+            | Error::NotFound { status_code, .. }
+            | Error::Validation { status_code, .. }
+            | Error::RateLimit { status_code, .. }
+            | Error::PaymentRequired { status_code, .. }
+            | Error::CreditsExhausted { status_code, .. }
+            | Error::Api { status_code, .. } => Some(*status_code).filter(|code| *code != 0),
+            Error::Network(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Check whether retrying the same request may succeed
+    ///
+    /// Rate limits, server errors, timeouts and connection failures are retryable;
+    /// authentication, validation and billing errors are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RateLimit { .. } | Error::Timeout => true,
+            Error::Api { status_code, .. } => *status_code >= 500,
+            Error::Network(err) => {
+                crate::client::is_transient(err) || err.status().is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn from_status_code(status_code: u16, message: String) -> Self {
         match status_code {
             401 => Error::Authentication {
                 message: "Authentication failed. Check your API key.".to_string(),
                 status_code,
             },
+            402 => Error::PaymentRequired {
+                message,
+                status_code,
+            },
             404 => Error::NotFound {
                 message: "Resource not found".to_string(),
                 status_code,
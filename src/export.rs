@@ -0,0 +1,60 @@
+//! CSV export helpers (requires the `csv` feature)
+
+use crate::{
+    error::Result,
+    types::{OfferWithHistory, ProductWithOffers},
+};
+use std::io::Write;
+
+/// Write price history to CSV, one row per history entry
+///
+/// Columns: `retailer`, `offer_id`, `date`, `price`, `availability`.
+pub fn write_price_history_csv<W: Write>(offers: &[OfferWithHistory], writer: W) -> Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(["retailer", "offer_id", "date", "price", "availability"])
+        .map_err(std::io::Error::from)?;
+
+    for offer in offers {
+        let retailer = offer.retailer.as_deref().unwrap_or_default();
+        for entry in &offer.price_history {
+            csv.write_record([
+                retailer,
+                offer.id.as_str(),
+                entry.date.as_str(),
+                entry.price.to_string().as_str(),
+                entry.availability.as_str(),
+            ])
+            .map_err(std::io::Error::from)?;
+        }
+    }
+
+    csv.flush()?;
+    Ok(())
+}
+
+/// Write current offers to CSV, one row per offer
+///
+/// Columns: `title`, `retailer`, `price`, `availability`, `url`. Missing
+/// values are written as empty cells.
+pub fn write_offers_csv<W: Write>(products: &[ProductWithOffers], writer: W) -> Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(["title", "retailer", "price", "availability", "url"])
+        .map_err(std::io::Error::from)?;
+
+    for product in products {
+        for offer in &product.offers {
+            let price = offer.price.map(|p| p.to_string()).unwrap_or_default();
+            csv.write_record([
+                product.title.as_str(),
+                offer.retailer.as_deref().unwrap_or_default(),
+                price.as_str(),
+                offer.availability.as_deref().unwrap_or_default(),
+                offer.url.as_deref().unwrap_or_default(),
+            ])
+            .map_err(std::io::Error::from)?;
+        }
+    }
+
+    csv.flush()?;
+    Ok(())
+}
@@ -0,0 +1,317 @@
+//! Product identifier parsing and classification
+
+use crate::error::{Error, Result};
+use std::borrow::Cow;
+
+/// A product identifier, classified by its format
+///
+/// Request methods accept anything that converts into a `ProductIdentifier`,
+/// so raw strings and parsed identifiers can both be passed; either way the
+/// identifier is validated before a request is sent. Parse with
+/// [`str::parse`] to catch invalid input up front.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let id: ProductIdentifier = "012345678901".parse()?;
+/// assert!(matches!(id, ProductIdentifier::Upc(_)));
+/// let product = client.get_product_details(&id, None, None).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProductIdentifier {
+    /// 12-digit UPC-A barcode
+    Upc(String),
+    /// 8- or 13-digit EAN barcode
+    Ean(String),
+    /// 10-character Amazon Standard Identification Number starting with `B0`
+    Asin(String),
+    /// Product page URL
+    Url(String),
+    /// ShopSavvy product ID, model number or other identifier
+    Id(String),
+}
+
+impl ProductIdentifier {
+    /// Get the identifier as sent to the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            ProductIdentifier::Upc(value)
+            | ProductIdentifier::Ean(value)
+            | ProductIdentifier::Asin(value)
+            | ProductIdentifier::Url(value)
+            | ProductIdentifier::Id(value) => value,
+        }
+    }
+}
+
+/// Classify a string without validating it
+///
+/// The value is kept as given; surrounding whitespace is ignored when
+/// classifying. Anything that is not a barcode, ASIN or URL becomes `Id`.
+impl<T: AsRef<str> + ?Sized> From<&T> for ProductIdentifier {
+    fn from(value: &T) -> Self {
+        let value = value.as_ref();
+        let trimmed = value.trim();
+
+        if trimmed.starts_with("http://") || trimmed.starts_with("https://") {
+            ProductIdentifier::Url(value.to_string())
+        } else if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_digit()) {
+            match trimmed.len() {
+                12 => ProductIdentifier::Upc(value.to_string()),
+                8 | 13 => ProductIdentifier::Ean(value.to_string()),
+                _ => ProductIdentifier::Id(value.to_string()),
+            }
+        } else if trimmed.len() == 10
+            && trimmed.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("b0"))
+            && trimmed.bytes().all(|b| b.is_ascii_alphanumeric())
+        {
+            ProductIdentifier::Asin(value.to_string())
+        } else {
+            ProductIdentifier::Id(value.to_string())
+        }
+    }
+}
+
+impl From<String> for ProductIdentifier {
+    fn from(value: String) -> Self {
+        ProductIdentifier::from(value.as_str())
+    }
+}
+
+/// Trim, validate and classify an identifier
+///
+/// Fails with `Error::Validation` for empty identifiers, malformed URLs and
+/// non-URL identifiers containing whitespace.
+impl std::str::FromStr for ProductIdentifier {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value = s.trim();
+        validate_identifier(value)?;
+        Ok(ProductIdentifier::from(value))
+    }
+}
+
+impl AsRef<str> for ProductIdentifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ProductIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Check that an identifier can be sent to the API, ignoring surrounding whitespace
+pub(crate) fn validate_identifier(identifier: &str) -> Result<()> {
+    let value = identifier.trim();
+    if value.is_empty() {
+        return Err(Error::validation("Identifier is empty"));
+    }
+
+    if value.starts_with("http://") || value.starts_with("https://") {
+        url::Url::parse(value).map_err(|err| Error::validation(format!("Invalid product URL {:?}: {}", value, err)))?;
+    } else if value.chars().any(char::is_whitespace) {
+        return Err(Error::validation(format!("Identifier {:?} contains whitespace", value)));
+    }
+    Ok(())
+}
+
+/// Clean up an identifier as typed or pasted by a user
+///
+/// Applied by the request methods unless disabled with
+/// `Config::with_identifier_normalization(false)`:
+///
+/// * Leading and trailing whitespace, including newlines, is trimmed.
+/// * ASINs (10 alphanumeric characters starting with `B0`) are uppercased.
+/// * Product URLs lose their `#fragment` and tracking query parameters
+///   (`utm_*`, click IDs such as `gclid`, and affiliate tags such as `tag`).
+///
+/// Everything else, including the case of other identifiers, is unchanged.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_eq!(normalize_identifier(" b08n5wrwnw\n"), "B08N5WRWNW");
+/// assert_eq!(
+///     normalize_identifier("https://example.com/p/123?utm_source=mail#reviews"),
+///     "https://example.com/p/123"
+/// );
+/// ```
+pub fn normalize_identifier(identifier: &str) -> Cow<'_, str> {
+    let identifier = identifier.trim();
+
+    match ProductIdentifier::from(identifier) {
+        ProductIdentifier::Asin(asin) if asin.bytes().any(|b| b.is_ascii_lowercase()) => {
+            Cow::Owned(asin.to_ascii_uppercase())
+        }
+        ProductIdentifier::Url(_) => match url::Url::parse(identifier) {
+            Ok(mut url) if url.fragment().is_some() || url.query_pairs().any(|(name, _)| is_tracking_param(&name)) => {
+                url.set_fragment(None);
+                strip_tracking_params(&mut url);
+                Cow::Owned(url.into())
+            }
+            _ => Cow::Borrowed(identifier),
+        },
+        _ => Cow::Borrowed(identifier),
+    }
+}
+
+/// Key under which two spellings of the same identifier compare equal
+///
+/// Barcodes lose dashes and spaces and are zero-padded to GTIN-14, so a UPC-A
+/// matches its EAN-13 form. Other identifiers are normalized and lowercased.
+pub(crate) fn identifier_key(identifier: &str) -> String {
+    let digits: String = identifier.trim().chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+    if (8..=14).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit()) {
+        return format!("{:0>14}", digits);
+    }
+    normalize_identifier(identifier).to_lowercase()
+}
+
+/// Remove tracking and affiliate query parameters from a URL, keeping the rest
+pub(crate) fn strip_tracking_params(url: &mut url::Url) {
+    let kept: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| !is_tracking_param(name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(&kept);
+    }
+}
+
+/// Check whether a query parameter only carries tracking or affiliate data
+fn is_tracking_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.starts_with("utm_")
+        || matches!(
+            name.as_str(),
+            "gclid" | "gbraid" | "wbraid" | "dclid" | "fbclid" | "msclkid" | "yclid" | "ttclid" | "twclid"
+                | "mc_cid" | "mc_eid" | "_ga" | "_gl" | "ref" | "ref_" | "tag" | "affid" | "affiliate"
+                | "affiliate_id" | "aff_id" | "clickid" | "click_id" | "irclickid" | "irgwc" | "cjevent"
+                | "ranmid" | "raneaid" | "ransiteid" | "sharedid" | "subid" | "campid" | "mkcid" | "mkrid"
+                | "mkevt" | "linkcode" | "ascsubtag" | "spm" | "srsltid"
+        )
+}
+
+/// Convert a 12-digit UPC-A barcode to its 13-digit EAN-13 form
+///
+/// The EAN-13 is the UPC with a leading zero; the check digit is recomputed
+/// and the input's own check digit is validated.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_eq!(upc_to_ean13("036000291452")?, "0036000291452");
+/// ```
+pub fn upc_to_ean13(upc: &str) -> Result<String> {
+    let upc = gtin_digits(upc, 12, "UPC-A")?;
+    Ok(with_check_digit(&format!("0{}", &upc[..11])))
+}
+
+/// Convert a 13-digit EAN-13 barcode to a 12-digit UPC-A
+///
+/// Only EAN-13 codes starting with `0` have a UPC-A equivalent; others
+/// return `Ok(None)`. The check digit is validated and recomputed.
+pub fn ean13_to_upc(ean: &str) -> Result<Option<String>> {
+    let ean = gtin_digits(ean, 13, "EAN-13")?;
+    Ok(ean.strip_prefix('0').map(|upc| with_check_digit(&upc[..11])))
+}
+
+/// Trim and validate a GTIN of the given length, including its check digit
+fn gtin_digits<'a>(value: &'a str, len: usize, kind: &str) -> Result<&'a str> {
+    let value = value.trim();
+    if value.len() != len || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::validation(format!("{} must be exactly {} digits, got {:?}", kind, len, value)));
+    }
+    if with_check_digit(&value[..len - 1]) != value {
+        return Err(Error::validation(format!("{} {} has an invalid check digit", kind, value)));
+    }
+    Ok(value)
+}
+
+/// Append the GS1 check digit to a string of digits
+fn with_check_digit(payload: &str) -> String {
+    let sum: u32 = payload
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    format!("{}{}", payload, (10 - sum % 10) % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_digit_matches_gs1() {
+        assert_eq!(with_check_digit("03600029145"), "036000291452");
+        assert_eq!(with_check_digit("400638133393"), "4006381333931");
+    }
+
+    #[test]
+    fn converts_between_upc_and_ean13() {
+        assert_eq!(upc_to_ean13(" 036000291452 ").unwrap(), "0036000291452");
+        assert_eq!(ean13_to_upc("0036000291452").unwrap().as_deref(), Some("036000291452"));
+        assert_eq!(ean13_to_upc("4006381333931").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_bad_gtins() {
+        for upc in ["036000291453", "03600029145", "03600029145a"] {
+            assert!(matches!(upc_to_ean13(upc), Err(Error::Validation { .. })), "{:?}", upc);
+        }
+        assert!(matches!(ean13_to_upc("0036000291453"), Err(Error::Validation { .. })));
+    }
+
+    #[test]
+    fn classifies_identifiers() {
+        assert_eq!(ProductIdentifier::from("012345678901"), ProductIdentifier::Upc("012345678901".to_string()));
+        assert_eq!(ProductIdentifier::from("4006381333931"), ProductIdentifier::Ean("4006381333931".to_string()));
+        assert_eq!(ProductIdentifier::from("96385074"), ProductIdentifier::Ean("96385074".to_string()));
+        assert_eq!(ProductIdentifier::from("b08n5wrwnw"), ProductIdentifier::Asin("b08n5wrwnw".to_string()));
+        assert_eq!(
+            ProductIdentifier::from("https://example.com/p/1"),
+            ProductIdentifier::Url("https://example.com/p/1".to_string())
+        );
+        assert_eq!(ProductIdentifier::from("WH1000XM5"), ProductIdentifier::Id("WH1000XM5".to_string()));
+        assert_eq!(ProductIdentifier::from("1234567890"), ProductIdentifier::Id("1234567890".to_string()));
+    }
+
+    #[test]
+    fn parsing_trims_and_validates() {
+        let id: ProductIdentifier = " 036000291452\n".parse().unwrap();
+        assert_eq!(id, ProductIdentifier::Upc("036000291452".to_string()));
+
+        for input in ["", "   ", "model 42", "https://exa mple.com/p"] {
+            assert!(matches!(input.parse::<ProductIdentifier>(), Err(Error::Validation { .. })), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn normalizes_asins_and_urls() {
+        assert_eq!(normalize_identifier(" b08n5wrwnw\n"), "B08N5WRWNW");
+        assert_eq!(normalize_identifier("https://example.com/p/123?utm_source=mail#reviews"), "https://example.com/p/123");
+        assert_eq!(
+            normalize_identifier("https://example.com/p/123?color=red&gclid=abc"),
+            "https://example.com/p/123?color=red"
+        );
+        assert_eq!(normalize_identifier("WH1000XM5/b"), "WH1000XM5/b");
+    }
+
+    #[test]
+    fn identifier_keys_ignore_barcode_formatting() {
+        assert_eq!(identifier_key("0-12345-67890-5"), identifier_key("012345678905"));
+        assert_eq!(identifier_key("012345678905"), identifier_key("0012345678905"));
+        assert_eq!(identifier_key("b08n5wrwnw"), identifier_key("B08N5WRWNW"));
+        assert_ne!(identifier_key("012345678905"), identifier_key("012345678906"));
+    }
+}
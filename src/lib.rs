@@ -12,20 +12,55 @@
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let client = Client::new("ss_live_your_api_key_here")?;
 //!
-//!     let product = client.get_product_details("012345678901", None).await?;
+//!     let product = client.get_product_details("012345678901", None, None).await?;
 //!     println!("Product: {}", product.data[0].title);
 //!
-//!     let offers = client.get_current_offers("012345678901", None, None).await?;
+//!     let offers = client.get_current_offers("012345678901", None, None, None).await?;
 //!     println!("Found {} offers", offers.data[0].offers.len());
 //!
 //!     Ok(())
 //! }
 //! ```
 
+pub mod api;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod circuit_breaker;
 pub mod client;
+mod dates;
 pub mod error;
+#[cfg(feature = "csv")]
+pub mod export;
+pub mod identifiers;
+pub mod observer;
+mod rate_limit;
+#[cfg(feature = "replay")]
+pub mod replay;
+pub mod retailer;
+pub mod retry;
+pub mod search;
+#[cfg(feature = "streaming")]
+mod streaming;
+#[cfg(feature = "metrics")]
+mod telemetry;
+pub mod time;
 pub mod types;
+pub mod usage;
 
-pub use client::Client;
+pub use api::ShopSavvyApi;
+#[cfg(feature = "cache")]
+pub use cache::CacheConfig;
+pub use circuit_breaker::CircuitBreakerConfig;
+pub use client::{cancellable, Client, ClientBuilder};
 pub use error::{Error, Result};
-pub use types::*;
\ No newline at end of file
+#[cfg(feature = "csv")]
+pub use export::{write_offers_csv, write_price_history_csv};
+pub use identifiers::{ean13_to_upc, normalize_identifier, upc_to_ean13, ProductIdentifier};
+pub use observer::{redact_url, RequestIdGenerator, RequestInfo, RequestObserver};
+#[cfg(feature = "replay")]
+pub use replay::Cassette;
+pub use retailer::{normalize_retailer, Retailer};
+pub use retry::{RetryClock, RetryConfig};
+pub use search::SearchRequest;
+pub use types::*;
+pub use usage::{RateLimitState, UsageSample, UsageTracker};
\ No newline at end of file
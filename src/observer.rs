@@ -0,0 +1,82 @@
+use regex::Regex;
+use serde_json::Value;
+use std::sync::{Arc, OnceLock};
+
+/// Details of an outgoing request passed to the request observer
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    pub method: String,
+    /// Full URL including query parameters, with credentials redacted
+    pub url: String,
+    pub body: Option<Value>,
+    /// Zero-based attempt number (greater than zero for retries)
+    pub attempt: u32,
+    /// Value of the `X-Request-Id` header, shared by all attempts of a call
+    pub request_id: String,
+}
+
+/// Callback invoked before every outgoing request
+#[derive(Clone)]
+pub struct RequestObserver(Arc<dyn Fn(&RequestInfo) + Send + Sync>);
+
+impl RequestObserver {
+    pub fn new(observer: impl Fn(&RequestInfo) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(observer))
+    }
+
+    pub(crate) fn notify(&self, info: &RequestInfo) {
+        (self.0)(info)
+    }
+}
+
+impl std::fmt::Debug for RequestObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestObserver(..)")
+    }
+}
+
+/// Source of the `X-Request-Id` sent with each call
+#[derive(Clone)]
+pub struct RequestIdGenerator(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl RequestIdGenerator {
+    pub fn new(generator: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(generator))
+    }
+
+    pub(crate) fn generate(&self) -> String {
+        (self.0)()
+    }
+}
+
+impl std::fmt::Debug for RequestIdGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestIdGenerator(..)")
+    }
+}
+
+/// Mask credentials in a URL before it is logged
+///
+/// Replaces the values of `api_key`, `apikey`, `key`, `token` and
+/// `access_token` query parameters, and any ShopSavvy API key appearing
+/// elsewhere in the URL, with `REDACTED`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_eq!(
+///     redact_url("https://api.shopsavvy.com/v1/usage?api_key=ss_live_abc123"),
+///     "https://api.shopsavvy.com/v1/usage?api_key=REDACTED"
+/// );
+/// ```
+pub fn redact_url(url: &str) -> String {
+    static QUERY_SECRET: OnceLock<Regex> = OnceLock::new();
+    static API_KEY: OnceLock<Regex> = OnceLock::new();
+
+    let query_secret = QUERY_SECRET
+        .get_or_init(|| Regex::new(r"(?i)([?&](?:api_key|apikey|key|token|access_token)=)[^&#]*").unwrap());
+    let api_key = API_KEY.get_or_init(|| Regex::new(r"ss_(live|test)_[a-zA-Z0-9]+").unwrap());
+
+    let redacted = query_secret.replace_all(url, "${1}REDACTED");
+    api_key.replace_all(&redacted, "ss_${1}_REDACTED").into_owned()
+}
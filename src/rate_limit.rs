@@ -0,0 +1,126 @@
+use crate::time::{Clock, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Client-side token bucket capping the outgoing request rate
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    bucket: Mutex<Bucket>,
+    rate: f64,
+    capacity: f64,
+    clock: Clock,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Allow `requests_per_second` on average with bursts of up to one second's worth
+    pub(crate) fn new(requests_per_second: f64) -> Self {
+        Self::with_clock(requests_per_second, Clock::System)
+    }
+
+    pub(crate) fn with_clock(requests_per_second: f64, clock: Clock) -> Self {
+        let capacity = requests_per_second.max(1.0);
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled_at: clock.now(),
+            }),
+            rate: requests_per_second,
+            capacity,
+            clock,
+        }
+    }
+
+    /// Wait until a request may be sent
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = self.clock.now();
+                let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+                bucket.refilled_at = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate)
+            };
+            self.clock.sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_second: f64) -> (RateLimiter, Clock) {
+        let clock = Clock::manual();
+        (RateLimiter::with_clock(requests_per_second, clock.clone()), clock)
+    }
+
+    #[tokio::test]
+    async fn allows_a_burst_then_waits_for_refill() {
+        let (limiter, clock) = limiter(20.0);
+        let started = clock.now();
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert_eq!(clock.now(), started);
+
+        // The bucket is empty, so the next token takes 1/20 s to refill
+        limiter.acquire().await;
+        assert_eq!(clock.now() - started, Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn refills_at_the_configured_rate() {
+        let (limiter, clock) = limiter(20.0);
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+
+        // 150 ms refills three tokens
+        clock.advance(Duration::from_millis(150));
+        let refilled = clock.now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert_eq!(clock.now(), refilled);
+
+        limiter.acquire().await;
+        assert!(clock.now() > refilled);
+    }
+
+    #[tokio::test]
+    async fn refill_is_capped_at_one_second_of_requests() {
+        let (limiter, clock) = limiter(5.0);
+        clock.advance(Duration::from_secs(60));
+        let started = clock.now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_eq!(clock.now(), started);
+
+        limiter.acquire().await;
+        assert_eq!(clock.now() - started, Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn slow_rates_still_allow_one_request() {
+        let (limiter, clock) = limiter(0.5);
+        let started = clock.now();
+        limiter.acquire().await;
+        assert_eq!(clock.now(), started);
+
+        limiter.acquire().await;
+        assert_eq!(clock.now() - started, Duration::from_secs(2));
+    }
+}
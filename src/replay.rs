@@ -0,0 +1,197 @@
+//! Record and replay HTTP interactions for deterministic tests
+
+use crate::error::{Error, Result};
+use crate::observer::redact_url;
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// VCR-style cassette of recorded API interactions
+///
+/// When the cassette file does not exist yet, requests go to the API and each
+/// interaction is appended to the file. Once it exists, requests are answered
+/// from the file without touching the network: each recorded interaction
+/// matching the method, URL and body is replayed once, in recording order.
+///
+/// Credentials are redacted from recorded URLs and `Authorization` headers.
+/// Requires the `replay` feature and a native target.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let config = Config::new(api_key).with_cassette(Cassette::open("tests/cassettes/lookup.json")?);
+/// let client = Client::with_config(config)?;
+/// let product = client.get_product_details("012345678901", None, None).await?;
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    replaying: bool,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    file: CassetteFile,
+    /// Which recorded interactions have already been replayed
+    replayed: Vec<bool>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
+impl RecordedRequest {
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.url == other.url && self.body == other.body
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+impl Cassette {
+    /// Open a cassette, replaying it if the file exists and recording otherwise
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let (replaying, file) = match std::fs::read_to_string(&path) {
+            Ok(contents) => (true, serde_json::from_str::<CassetteFile>(&contents)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => (false, CassetteFile::default()),
+            Err(err) => return Err(err.into()),
+        };
+        let replayed = vec![false; file.interactions.len()];
+        Ok(Self {
+            path,
+            replaying,
+            state: Mutex::new(State { file, replayed }),
+        })
+    }
+
+    /// Check whether requests are answered from the file rather than the API
+    pub fn is_replaying(&self) -> bool {
+        self.replaying
+    }
+
+    /// Send a request through the cassette
+    ///
+    /// The outer result fails when no recorded interaction matches or the
+    /// cassette cannot be written; the inner one carries transport errors.
+    pub(crate) async fn execute(&self, client: &reqwest::Client, request: reqwest::Request) -> Result<reqwest::Result<reqwest::Response>> {
+        let recorded_request = record_request(&request);
+        if self.replaying {
+            return self.replay(&recorded_request).map(Ok);
+        }
+
+        let response = match client.execute(request).await {
+            Ok(response) => response,
+            Err(err) => return Ok(Err(err)),
+        };
+        let status = response.status().as_u16();
+        let headers = header_map(response.headers());
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let recorded_response = RecordedResponse { status, headers, body };
+        let response = to_response(&recorded_response)?;
+        self.append(Interaction {
+            request: recorded_request,
+            response: recorded_response,
+        })?;
+        Ok(Ok(response))
+    }
+
+    /// Answer a request with the first matching interaction not yet replayed
+    fn replay(&self, request: &RecordedRequest) -> Result<reqwest::Response> {
+        let mut state = self.state.lock().unwrap();
+        let State { file, replayed } = &mut *state;
+        let index = file
+            .interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, used)| !used && interaction.request.matches(request))
+            .ok_or_else(|| {
+                Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!(
+                        "No recorded interaction for {} {} in {}",
+                        request.method,
+                        request.url,
+                        self.path.display()
+                    ),
+                ))
+            })?;
+        replayed[index] = true;
+        to_response(&file.interactions[index].response)
+    }
+
+    /// Record an interaction and rewrite the cassette file
+    fn append(&self, interaction: Interaction) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.file.interactions.push(interaction);
+        std::fs::write(&self.path, serde_json::to_string_pretty(&state.file)?)?;
+        Ok(())
+    }
+}
+
+/// Capture the parts of a request that identify it, with credentials redacted
+fn record_request(request: &reqwest::Request) -> RecordedRequest {
+    let mut headers = header_map(request.headers());
+    if let Some(value) = headers.get_mut(AUTHORIZATION.as_str()) {
+        *value = "REDACTED".to_string();
+    }
+
+    RecordedRequest {
+        method: request.method().to_string(),
+        url: redact_url(request.url().as_str()),
+        headers,
+        body: request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned()),
+    }
+}
+
+fn header_map(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+/// Rebuild a response from its recording
+fn to_response(recorded: &RecordedResponse) -> Result<reqwest::Response> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    let response = builder
+        .body(recorded.body.clone())
+        .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err)))?;
+    Ok(reqwest::Response::from(response))
+}
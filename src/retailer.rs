@@ -0,0 +1,94 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Normalize a retailer name to a comparable slug
+///
+/// Lowercases, drops a trailing `.com` and removes everything except ASCII
+/// letters and digits, so `"Best Buy"`, `"bestbuy"` and `"BestBuy.com"` all
+/// normalize to `"bestbuy"`.
+pub fn normalize_retailer(name: &str) -> String {
+    let lower = name.trim().to_ascii_lowercase();
+    let name = lower.strip_suffix(".com").unwrap_or(&lower);
+    name.chars().filter(|c| c.is_ascii_alphanumeric()).collect()
+}
+
+/// Retailer identified by its normalized slug
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Retailer {
+    Amazon,
+    Walmart,
+    Target,
+    BestBuy,
+    Costco,
+    HomeDepot,
+    Lowes,
+    Ebay,
+    Newegg,
+    /// Any other retailer, stored as its normalized slug
+    Other(String),
+}
+
+impl Retailer {
+    /// Retailers known to this SDK version
+    pub const KNOWN: &'static [Retailer] = &[
+        Retailer::Amazon,
+        Retailer::Walmart,
+        Retailer::Target,
+        Retailer::BestBuy,
+        Retailer::Costco,
+        Retailer::HomeDepot,
+        Retailer::Lowes,
+        Retailer::Ebay,
+        Retailer::Newegg,
+    ];
+
+    /// Get the normalized slug
+    pub fn slug(&self) -> &str {
+        match self {
+            Retailer::Amazon => "amazon",
+            Retailer::Walmart => "walmart",
+            Retailer::Target => "target",
+            Retailer::BestBuy => "bestbuy",
+            Retailer::Costco => "costco",
+            Retailer::HomeDepot => "homedepot",
+            Retailer::Lowes => "lowes",
+            Retailer::Ebay => "ebay",
+            Retailer::Newegg => "newegg",
+            Retailer::Other(slug) => slug,
+        }
+    }
+
+    /// Check whether a retailer name refers to this retailer, ignoring formatting
+    pub fn matches(&self, name: &str) -> bool {
+        self.slug() == normalize_retailer(name)
+    }
+}
+
+impl From<&str> for Retailer {
+    fn from(value: &str) -> Self {
+        let slug = normalize_retailer(value);
+        Retailer::KNOWN
+            .iter()
+            .find(|known| known.slug() == slug)
+            .cloned()
+            .unwrap_or(Retailer::Other(slug))
+    }
+}
+
+impl std::fmt::Display for Retailer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slug())
+    }
+}
+
+impl Serialize for Retailer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.slug())
+    }
+}
+
+impl<'de> Deserialize<'de> for Retailer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Retailer::from(value.as_str()))
+    }
+}
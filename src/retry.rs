@@ -0,0 +1,158 @@
+use crate::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Retry settings for transient failures (429, 5xx, connection errors)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    #[serde(with = "crate::time::millis")]
+    pub base_delay: Duration,
+    #[serde(with = "crate::time::millis")]
+    pub max_delay: Duration,
+    /// Randomize each delay into 50%..100% of the backoff (enabled by default)
+    pub jitter: bool,
+    /// Called with each delay instead of sleeping, when set
+    #[serde(skip)]
+    pub clock: Option<RetryClock>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            clock: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable jitter, making backoff delays exact when disabled
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Hand retry delays to a clock instead of sleeping
+    ///
+    /// Useful in tests to assert exact backoff durations without waiting.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let delays = Arc::new(Mutex::new(Vec::new()));
+    /// let recorded = delays.clone();
+    /// let retry = RetryConfig::new(3)
+    ///     .with_jitter(false)
+    ///     .with_clock(RetryClock::new(move |delay| recorded.lock().unwrap().push(delay)));
+    /// ```
+    pub fn with_clock(mut self, clock: RetryClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Exponential backoff delay for a zero-based attempt, with jitter
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+
+        // Scale into 50%..100% of the delay so concurrent clients spread out
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        delay.mul_f64(0.5 + f64::from(nanos % 1000) / 2000.0)
+    }
+}
+
+/// Callback that receives retry delays in place of a real sleep
+#[derive(Clone)]
+pub struct RetryClock(Arc<dyn Fn(Duration) + Send + Sync>);
+
+impl RetryClock {
+    pub fn new(clock: impl Fn(Duration) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(clock))
+    }
+
+    pub(crate) fn advance(&self, delay: Duration) {
+        (self.0)(delay)
+    }
+}
+
+impl std::fmt::Debug for RetryClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RetryClock(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let retry = RetryConfig::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(300))
+            .with_jitter(false);
+
+        assert_eq!(retry.backoff(0), Duration::from_millis(100));
+        assert_eq!(retry.backoff(1), Duration::from_millis(200));
+        assert_eq!(retry.backoff(2), Duration::from_millis(300));
+        assert_eq!(retry.backoff(64), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn jitter_stays_within_half_to_full_delay() {
+        let retry = RetryConfig::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1));
+
+        for attempt in 0..8 {
+            let full = Duration::from_millis(100).saturating_mul(1 << attempt).min(Duration::from_secs(1));
+            for _ in 0..50 {
+                let delay = retry.backoff(attempt);
+                assert!(delay >= full / 2 && delay <= full, "attempt {}: {:?} not in {:?}..={:?}", attempt, delay, full / 2, full);
+            }
+        }
+    }
+
+    #[test]
+    fn clock_receives_delays() {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded = delays.clone();
+        let clock = RetryClock::new(move |delay| recorded.lock().unwrap().push(delay));
+
+        clock.advance(Duration::from_millis(5));
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(*delays.lock().unwrap(), [Duration::from_millis(5), Duration::from_millis(10)]);
+    }
+}
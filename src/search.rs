@@ -0,0 +1,83 @@
+//! Product search with filters
+
+use crate::{client::Client, error::Result, types::ProductSearchResult};
+
+/// Builder for a filtered product search, created by [`Client::search`]
+///
+/// Filters that are not set are not sent. Use [`param`](SearchRequest::param)
+/// for filters the builder has no dedicated method for.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let results = client
+///     .search("headphones")
+///     .brand("Sony")
+///     .category("Electronics")
+///     .min_price(50.0)
+///     .max_price(200.0)
+///     .limit(25)
+///     .send()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "a search does nothing until `send` is awaited"]
+pub struct SearchRequest<'a> {
+    client: &'a Client,
+    params: Vec<(String, String)>,
+}
+
+impl<'a> SearchRequest<'a> {
+    pub(crate) fn new(client: &'a Client, query: &str) -> Self {
+        Self {
+            client,
+            params: vec![("q".to_string(), query.to_string())],
+        }
+    }
+
+    /// Only return products from this brand
+    pub fn brand(self, brand: impl Into<String>) -> Self {
+        self.param("brand", brand)
+    }
+
+    /// Only return products in this category
+    pub fn category(self, category: impl Into<String>) -> Self {
+        self.param("category", category)
+    }
+
+    /// Only return products priced at or above this amount
+    pub fn min_price(self, min_price: f64) -> Self {
+        self.param("min_price", min_price.to_string())
+    }
+
+    /// Only return products priced at or below this amount
+    pub fn max_price(self, max_price: f64) -> Self {
+        self.param("max_price", max_price.to_string())
+    }
+
+    /// Maximum number of results
+    pub fn limit(self, limit: i32) -> Self {
+        self.param("limit", limit.to_string())
+    }
+
+    /// Pagination offset
+    pub fn offset(self, offset: i32) -> Self {
+        self.param("offset", offset.to_string())
+    }
+
+    /// Set any other query parameter, replacing an earlier value for the same name
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.params.retain(|(existing, _)| *existing != name);
+        self.params.push((name, value.into()));
+        self
+    }
+
+    /// Run the search
+    pub async fn send(self) -> Result<ProductSearchResult> {
+        let params: Vec<(&str, &str)> = self.params.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        self.client
+            .request_raw(reqwest::Method::GET, "/products/search", Some(&params))
+            .await
+    }
+}
@@ -0,0 +1,93 @@
+//! Incremental JSON deserialization of large response bodies
+
+use crate::client::json_path_error;
+use crate::error::{Error, Result};
+use std::io::{BufReader, Read};
+use tokio::sync::mpsc;
+
+/// Downloaded chunks buffered ahead of the parser
+const CHANNEL_CHUNKS: usize = 16;
+
+/// Deserialize a response body while it downloads, without buffering it whole
+///
+/// The parser runs on a blocking thread and reads chunks from a bounded
+/// channel, so peak memory is the parsed value plus a few chunks. Bodies over
+/// `limit` bytes fail with [`Error::ResponseTooLarge`].
+pub(crate) async fn deserialize_response<T>(mut response: reqwest::Response, limit: usize) -> Result<T>
+where
+    T: for<'de> serde::Deserialize<'de> + Send + 'static,
+{
+    if response.content_length().is_some_and(|len| len > limit as u64) {
+        return Err(Error::ResponseTooLarge { limit });
+    }
+
+    let (sender, receiver) = mpsc::channel(CHANNEL_CHUNKS);
+    let parser = tokio::task::spawn_blocking(move || {
+        let reader = BufReader::new(ChannelReader {
+            receiver,
+            chunk: Vec::new(),
+            position: 0,
+        });
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let value: T = serde_path_to_error::deserialize(&mut deserializer).map_err(json_path_error)?;
+        deserializer.end()?;
+        Ok(value)
+    });
+
+    // Feed chunks until the body ends, it grows too large, or the parser stops early
+    let mut received = 0;
+    let mut download = Ok(());
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                received += chunk.len();
+                if received > limit {
+                    download = Err(Error::ResponseTooLarge { limit });
+                    break;
+                }
+                if sender.send(chunk.to_vec()).await.is_err() {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(err) => {
+                download = Err(err.into());
+                break;
+            }
+        }
+    }
+    drop(sender);
+
+    let parsed = parser
+        .await
+        .map_err(|err| Error::Io(std::io::Error::other(err)))?;
+    // A cut-off download also fails the parse; report the underlying cause
+    download?;
+    parsed
+}
+
+/// Blocking reader over body chunks sent from the download task
+struct ChannelReader {
+    receiver: mpsc::Receiver<Vec<u8>>,
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position == self.chunk.len() {
+            match self.receiver.blocking_recv() {
+                Some(chunk) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.chunk.len() - self.position);
+        buf[..len].copy_from_slice(&self.chunk[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
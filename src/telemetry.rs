@@ -0,0 +1,23 @@
+//! Request metrics via the `metrics` facade (requires the `metrics` feature)
+//!
+//! Install any `metrics` recorder, such as `metrics-exporter-prometheus`, to
+//! export these:
+//!
+//! - `shopsavvy_requests_total{endpoint,status}`: counter of HTTP attempts,
+//!   with `status` set to the HTTP status code or `error` for transport failures
+//! - `shopsavvy_request_duration_seconds{endpoint}`: histogram of attempt latency
+//! - `shopsavvy_credits_remaining`: gauge of the last reported credit balance
+
+use std::time::Duration;
+
+/// Record one HTTP attempt against an endpoint
+pub(crate) fn record_request(endpoint: &str, status: Option<u16>, elapsed: Duration) {
+    let status = status.map_or_else(|| "error".to_string(), |code| code.to_string());
+    metrics::counter!("shopsavvy_requests_total", "endpoint" => endpoint.to_string(), "status" => status).increment(1);
+    metrics::histogram!("shopsavvy_request_duration_seconds", "endpoint" => endpoint.to_string()).record(elapsed.as_secs_f64());
+}
+
+/// Record the credit balance reported by a response
+pub(crate) fn record_credits_remaining(credits_remaining: i32) {
+    metrics::gauge!("shopsavvy_credits_remaining").set(f64::from(credits_remaining));
+}
@@ -0,0 +1,88 @@
+//! Clock and timer primitives that work on both native and WASM targets
+
+#[cfg(not(feature = "wasm"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(feature = "wasm")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Sleep for the given duration
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    #[cfg(not(feature = "wasm"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(feature = "wasm")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Serialize a `Duration` as whole milliseconds, for config files
+pub(crate) mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
+}
+
+/// Serialize an optional `Duration` as whole milliseconds or `null`
+pub(crate) mod option_millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => super::millis::serialize(duration, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
+}
+
+/// Time source for the circuit breaker and rate limiter
+///
+/// `Clock::System` reads the real clock and sleeps for real. A manual clock,
+/// like [`RetryClock`](crate::retry::RetryClock) for retries, lets tests move
+/// time forward: its `sleep` advances the clock instead of waiting.
+#[derive(Debug, Clone)]
+pub(crate) enum Clock {
+    System,
+    #[cfg_attr(not(test), allow(dead_code))]
+    Manual(std::sync::Arc<std::sync::Mutex<Instant>>),
+}
+
+impl Clock {
+    /// A manual clock starting at the current instant
+    #[cfg(test)]
+    pub(crate) fn manual() -> Self {
+        Clock::Manual(std::sync::Arc::new(std::sync::Mutex::new(Instant::now())))
+    }
+
+    pub(crate) fn now(&self) -> Instant {
+        match self {
+            Clock::System => Instant::now(),
+            Clock::Manual(now) => *now.lock().unwrap(),
+        }
+    }
+
+    /// Sleep for real, or move a manual clock forward without waiting
+    pub(crate) async fn sleep(&self, duration: std::time::Duration) {
+        match self {
+            Clock::System => sleep(duration).await,
+            Clock::Manual(now) => *now.lock().unwrap() += duration,
+        }
+    }
+
+    /// Move a manual clock forward; the system clock cannot be moved
+    #[cfg(test)]
+    pub(crate) fn advance(&self, duration: std::time::Duration) {
+        if let Clock::Manual(now) = self {
+            *now.lock().unwrap() += duration;
+        }
+    }
+}
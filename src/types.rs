@@ -1,47 +1,700 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+use crate::circuit_breaker::CircuitBreakerConfig;
+use crate::error::{Error, Result};
+use crate::identifiers::{identifier_key, strip_tracking_params};
+use crate::observer::{RequestIdGenerator, RequestInfo, RequestObserver};
+use crate::retailer::normalize_retailer;
+use crate::retry::RetryConfig;
+#[cfg(feature = "cache")]
+use crate::cache::CacheConfig;
+#[cfg(feature = "replay")]
+use crate::replay::Cassette;
+
+/// Default cap on response body size (32 MiB)
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 32 * 1024 * 1024;
+
+/// Default cap on identifiers per batch request, the server limit on Pro plans
+const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+/// Default cap on requests in flight for methods that fan out per identifier
+const DEFAULT_FAN_OUT_CONCURRENCY: usize = 4;
 
 /// Configuration for the ShopSavvy API client
-#[derive(Debug, Clone)]
+///
+/// Serializable so settings can be loaded from a TOML or JSON file. Missing
+/// fields take their [`Default`] values. The API key is never read from or
+/// written to the file; it comes from `SHOPSAVVY_API_KEY` or can be set after
+/// loading. The request observer and request ID generator are not serialized
+/// either, and default headers are read but never written back since they may
+/// carry credentials. Durations are written as whole milliseconds.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut config: Config = toml::from_str(&std::fs::read_to_string("shopsavvy.toml")?)?;
+/// config.api_key = secrets.get("shopsavvy")?;
+/// let client = Client::with_config(config)?;
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
+    #[serde(skip)]
     pub api_key: String,
     pub base_url: String,
+    #[serde(with = "crate::time::millis")]
     pub timeout: std::time::Duration,
+    /// Maximum time to establish a connection (no separate limit when `None`)
+    #[serde(with = "crate::time::option_millis")]
+    pub connect_timeout: Option<std::time::Duration>,
+    /// Extra default headers sent with every request
+    #[serde(skip_serializing)]
+    pub headers: Vec<(String, String)>,
+    /// Product token prepended to the SDK's User-Agent
+    pub user_agent: Option<String>,
+    /// Preferred language for product data, sent as `Accept-Language`
+    pub language: Option<String>,
+    /// Retry settings for transient failures (disabled when `None`)
+    pub retry: Option<RetryConfig>,
+    /// Maximum outgoing requests per second (unlimited when `None`)
+    pub rate_limit: Option<f64>,
+    /// Fail fast after repeated failures (disabled when `None`)
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Maximum idle connections kept per host (reqwest default when `None`)
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long idle connections are kept open (reqwest default when `None`)
+    #[serde(with = "crate::time::option_millis")]
+    pub pool_idle_timeout: Option<std::time::Duration>,
+    /// Use HTTP/2 without ALPN negotiation
+    pub http2_prior_knowledge: bool,
+    /// Skip TLS certificate verification (testing only, see `danger_accept_invalid_certs`)
+    pub danger_accept_invalid_certs: bool,
+    /// Callback invoked before every outgoing request
+    #[serde(skip)]
+    pub observer: Option<RequestObserver>,
+    /// Generates the `X-Request-Id` of each call (a random UUID when `None`)
+    #[serde(skip)]
+    pub request_id_generator: Option<RequestIdGenerator>,
+    /// Build requests without sending them
+    pub dry_run: bool,
+    /// Send schedule removals as query parameters instead of a DELETE body
+    pub delete_via_query: bool,
+    /// Largest response body read before failing with `Error::ResponseTooLarge`
+    pub max_response_bytes: usize,
+    /// Most identifiers sent in one batch request
+    pub max_batch_size: usize,
+    /// Most requests in flight for methods that send one request per identifier
+    pub fan_out_concurrency: usize,
+    /// Trim and clean up identifiers before sending them (enabled by default)
+    pub normalize_identifiers: bool,
+    /// Product details cache settings (disabled when `None`)
+    #[cfg(feature = "cache")]
+    pub cache: Option<CacheConfig>,
+    /// Cassette that records or replays all HTTP interactions
+    #[cfg(feature = "replay")]
+    #[serde(skip)]
+    pub cassette: Option<std::sync::Arc<Cassette>>,
 }
 
 impl Config {
+    /// Start building a configuration with validation
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use shopsavvy_sdk::Config;
+    /// use std::time::Duration;
+    ///
+    /// let config = Config::builder()
+    ///     .api_key("ss_live_your_api_key_here")
+    ///     .timeout(Duration::from_secs(60))
+    ///     .max_retries(3)
+    ///     .user_agent("MyApp/1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
             api_key: api_key.into(),
             base_url: "https://api.shopsavvy.com/v1".to_string(),
             timeout: std::time::Duration::from_secs(30),
+            connect_timeout: None,
+            headers: Vec::new(),
+            user_agent: None,
+            language: None,
+            retry: None,
+            rate_limit: None,
+            circuit_breaker: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http2_prior_knowledge: false,
+            danger_accept_invalid_certs: false,
+            observer: None,
+            request_id_generator: None,
+            dry_run: false,
+            delete_via_query: false,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            fan_out_concurrency: DEFAULT_FAN_OUT_CONCURRENCY,
+            normalize_identifiers: true,
+            #[cfg(feature = "cache")]
+            cache: None,
+            #[cfg(feature = "replay")]
+            cassette: None,
         }
     }
 
+    /// Send requests to a different API root
+    ///
+    /// The URL may include a path prefix, such as a gateway mount like
+    /// `https://gateway.internal/external/shopsavvy/v1`; a trailing slash is optional.
     pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
         self.base_url = base_url.into();
         self
     }
 
+    /// Total time allowed for a request, from connecting until the body is read
     pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
         self.timeout = timeout;
         self
     }
+
+    /// Time allowed to establish a connection, including the TLS handshake
+    ///
+    /// The connect timeout only bounds the connection phase, while the overall
+    /// `timeout` still covers the whole request, connection included. A connect
+    /// timeout longer than the overall timeout therefore has no effect. Ignored
+    /// by the `wasm` feature.
+    pub fn with_connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Add a default header sent with every request
+    ///
+    /// Names and values are validated when the client is built. The
+    /// `Authorization` header is reserved for the API key and cannot be set here.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Add several default headers sent with every request
+    pub fn with_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        for (name, value) in &headers {
+            self.headers.push((name.as_str().to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()));
+        }
+        self
+    }
+
+    /// Identify your application in the User-Agent header
+    ///
+    /// The value is prepended to the SDK's own token, e.g. `MyApp/2.0` becomes
+    /// `MyApp/2.0 ShopSavvy-Rust-SDK/1.0.1`, and is validated when the client is built.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Request product data in a language, e.g. `"de"` or `"de-DE"`
+    ///
+    /// Sent as the `Accept-Language` header on every request and validated as a
+    /// language tag when the client is built. Use
+    /// [`Client::with_language`](crate::Client::with_language) to override it
+    /// for individual calls.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Retry rate-limited, server-error and connection failures with exponential backoff
+    ///
+    /// A 429 is not retried once the account is known to have no credits left;
+    /// it fails immediately with `Error::CreditsExhausted` instead.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Cap the outgoing request rate with a client-side token bucket
+    ///
+    /// Requests wait for a free slot before being sent. The budget is shared by
+    /// all clones of the client, so concurrent tasks cannot exceed it together.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.rate_limit = Some(requests_per_second);
+        self
+    }
+
+    /// Stop sending requests for `cooldown` after `failure_threshold` failures within `window`
+    ///
+    /// Connection failures and 5xx responses count as failures. While the
+    /// circuit is open, requests fail immediately with `Error::CircuitOpen`;
+    /// after the cooldown one probe request decides whether it closes again.
+    /// The breaker is shared by all clones of the client.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, window: std::time::Duration, cooldown: std::time::Duration) -> Self {
+        self.circuit_breaker = Some(CircuitBreakerConfig {
+            failure_threshold,
+            window,
+            cooldown,
+        });
+        self
+    }
+
+    /// Limit the number of idle connections kept open per host
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Close idle pooled connections after the given duration
+    pub fn with_pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Speak HTTP/2 from the first byte instead of negotiating it
+    ///
+    /// Only enable this when the server (or gateway) is known to support HTTP/2.
+    /// Pool and HTTP/2 settings are ignored by the `wasm` feature.
+    pub fn with_http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Accept invalid TLS certificates, including self-signed and expired ones
+    ///
+    /// # Warning
+    ///
+    /// This disables certificate verification entirely: any server can
+    /// impersonate the API and read your API key and traffic. Only enable it to
+    /// test against a local mock server over self-signed HTTPS, never in
+    /// production. Requires the `native` feature.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Register a callback invoked with the details of every outgoing request
+    pub fn with_request_observer(mut self, observer: impl Fn(&RequestInfo) + Send + Sync + 'static) -> Self {
+        self.observer = Some(RequestObserver::new(observer));
+        self
+    }
+
+    /// Generate the `X-Request-Id` header of each call with a custom function
+    ///
+    /// Every call sends an `X-Request-Id`, a random UUID by default, reused by
+    /// its retries and reported in [`RequestInfo::request_id`]. Use this to
+    /// propagate correlation IDs from your own tracing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let config = Config::new(api_key)
+    ///     .with_request_id_generator(|| format!("checkout-{}", next_trace_id()));
+    /// ```
+    pub fn with_request_id_generator(mut self, generator: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        self.request_id_generator = Some(RequestIdGenerator::new(generator));
+        self
+    }
+
+    /// Build requests without sending them
+    ///
+    /// Each call builds the full URL and body, notifies the request observer and
+    /// then fails with `Error::DryRun` instead of contacting the API, so no
+    /// credits are spent.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Send the identifiers of schedule removals as query parameters
+    ///
+    /// Some proxies and gateways strip the body from DELETE requests, which
+    /// makes removals silently do nothing. With this enabled the removal
+    /// methods send `identifier`/`identifiers` in the query string and no body.
+    pub fn with_delete_via_query(mut self, enabled: bool) -> Self {
+        self.delete_via_query = enabled;
+        self
+    }
+
+    /// Limit how many bytes of a response body are read
+    ///
+    /// Bodies are read in chunks and the request fails with
+    /// `Error::ResponseTooLarge` as soon as the limit is exceeded, so a huge
+    /// response cannot exhaust memory. Defaults to 32 MiB.
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = max_bytes;
+        self
+    }
+
+    /// Limit how many identifiers a batch method sends in one request
+    ///
+    /// Batch methods fail with a validation error naming the limit before
+    /// sending more identifiers than this; split larger lists with
+    /// [`slice::chunks`]. Defaults to 100, the server limit on Pro plans (Free
+    /// plans allow 10, Enterprise plans 1000).
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Limit how many requests run at once in methods that fan out per identifier
+    ///
+    /// Applies to methods such as `get_price_history_batch` that send one
+    /// request per identifier. Defaults to 4; the configured rate limit still
+    /// applies on top.
+    pub fn with_fan_out_concurrency(mut self, concurrency: usize) -> Self {
+        self.fan_out_concurrency = concurrency;
+        self
+    }
+
+    /// Enable or disable cleaning up identifiers before they are sent
+    ///
+    /// Enabled by default. Identifiers are trimmed, ASINs uppercased and
+    /// tracking parameters stripped from product URLs; see
+    /// [`normalize_identifier`](crate::identifiers::normalize_identifier) for
+    /// the exact rules. Disable it to send identifiers exactly as given.
+    pub fn with_identifier_normalization(mut self, enabled: bool) -> Self {
+        self.normalize_identifiers = enabled;
+        self
+    }
+
+    /// Cache product details responses in memory
+    ///
+    /// Only product details lookups are cached; offers and price history are
+    /// always fetched fresh since they are time-sensitive. Enabling the cache
+    /// also stores ETags for product details lookups and sends `If-None-Match`
+    /// on repeats, serving the stored body when the server answers 304 Not
+    /// Modified.
+    #[cfg(feature = "cache")]
+    pub fn with_cache(mut self, capacity: usize, ttl: std::time::Duration) -> Self {
+        self.cache = Some(CacheConfig { capacity, ttl });
+        self
+    }
+
+    /// Record API interactions to a cassette file, or replay them from it
+    ///
+    /// See [`Cassette`] for how recording and replaying are chosen.
+    #[cfg(feature = "replay")]
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(std::sync::Arc::new(cassette));
+        self
+    }
+
+    /// Check the configuration for invalid values
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.timeout.is_zero() {
+            return Err(Error::validation("Timeout must be greater than zero"));
+        }
+
+        if let Some(language) = &self.language {
+            if !is_language_tag(language) {
+                return Err(Error::validation(format!("Invalid language tag: {}", language)));
+            }
+        }
+
+        if self.max_response_bytes == 0 {
+            return Err(Error::validation("Maximum response size must be greater than zero"));
+        }
+
+        if self.max_batch_size == 0 {
+            return Err(Error::validation("Maximum batch size must be greater than zero"));
+        }
+
+        if self.fan_out_concurrency == 0 {
+            return Err(Error::validation("Fan-out concurrency must be greater than zero"));
+        }
+
+        if self.connect_timeout.is_some_and(|timeout| timeout.is_zero()) {
+            return Err(Error::validation("Connect timeout must be greater than zero"));
+        }
+
+        if url::Url::parse(&self.base_url).is_err() {
+            return Err(Error::validation(format!("Invalid base URL: {}", self.base_url)));
+        }
+
+        if let Some(rate) = self.rate_limit {
+            if rate <= 0.0 || !rate.is_finite() {
+                return Err(Error::validation("Rate limit must be a positive number of requests per second"));
+            }
+        }
+
+        if let Some(breaker) = &self.circuit_breaker {
+            if breaker.failure_threshold == 0 || breaker.cooldown.is_zero() {
+                return Err(Error::validation("Circuit breaker threshold and cooldown must be greater than zero"));
+            }
+        }
+
+        if let Some(retry) = &self.retry {
+            if retry.max_delay < retry.base_delay {
+                return Err(Error::validation("Retry max_delay must not be shorter than base_delay"));
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            if cache.capacity == 0 || cache.ttl.is_zero() {
+                return Err(Error::validation("Cache capacity and TTL must be greater than zero"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check for a well-formed BCP 47 language tag such as `de` or `pt-BR`
+pub(crate) fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let primary_ok = subtags
+        .next()
+        .is_some_and(|primary| (2..=8).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic()));
+    primary_ok && subtags.all(|subtag| (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Production settings with the API key read from `SHOPSAVVY_API_KEY`
+///
+/// The API key is left empty when the variable is unset, so building a client
+/// from the default configuration still fails with `Error::MissingApiKey`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let config = Config {
+///     timeout: Duration::from_secs(60),
+///     ..Default::default()
+/// };
+/// ```
+impl Default for Config {
+    fn default() -> Self {
+        Self::new(std::env::var("SHOPSAVVY_API_KEY").unwrap_or_default())
+    }
+}
+
+/// Builder for [`Config`] that validates all options in [`build`](ConfigBuilder::build)
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder {
+    config: Config,
+    /// Whether `timeout` was called, so `ClientBuilder` keeps it for test keys
+    pub(crate) timeout_set: bool,
+    #[cfg(feature = "cache")]
+    cache_capacity: Option<usize>,
+    #[cfg(feature = "cache")]
+    cache_ttl: Option<std::time::Duration>,
+}
+
+/// Starts from [`Config::default`], so the API key is read from `SHOPSAVVY_API_KEY`
+/// unless set with [`api_key`](ConfigBuilder::api_key).
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self {
+            config: Config::default(),
+            timeout_set: false,
+            #[cfg(feature = "cache")]
+            cache_capacity: None,
+            #[cfg(feature = "cache")]
+            cache_ttl: None,
+        }
+    }
+}
+
+impl ConfigBuilder {
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.config.api_key = api_key.into();
+        self
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config = self.config.with_base_url(base_url);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.config = self.config.with_timeout(timeout);
+        self.timeout_set = true;
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.config = self.config.with_connect_timeout(timeout);
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config = self.config.with_header(name, value);
+        self
+    }
+
+    pub fn headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.config = self.config.with_headers(headers);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config = self.config.with_user_agent(user_agent);
+        self
+    }
+
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.config = self.config.with_language(language);
+        self
+    }
+
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.config = self.config.with_retry(retry);
+        self
+    }
+
+    /// Retry up to `max_retries` times with the default backoff
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        let retry = self.config.retry.take().unwrap_or_default();
+        self.config.retry = Some(RetryConfig { max_retries, ..retry });
+        self
+    }
+
+    pub fn rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.config = self.config.with_rate_limit(requests_per_second);
+        self
+    }
+
+    pub fn circuit_breaker(mut self, failure_threshold: u32, window: std::time::Duration, cooldown: std::time::Duration) -> Self {
+        self.config = self.config.with_circuit_breaker(failure_threshold, window, cooldown);
+        self
+    }
+
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.config = self.config.with_pool_max_idle_per_host(max_idle);
+        self
+    }
+
+    pub fn pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.config = self.config.with_pool_idle_timeout(timeout);
+        self
+    }
+
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.config = self.config.with_http2_prior_knowledge(enabled);
+        self
+    }
+
+    /// See [`Config::danger_accept_invalid_certs`] for the security implications
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.config = self.config.danger_accept_invalid_certs(accept);
+        self
+    }
+
+    pub fn request_observer(mut self, observer: impl Fn(&RequestInfo) + Send + Sync + 'static) -> Self {
+        self.config = self.config.with_request_observer(observer);
+        self
+    }
+
+    pub fn request_id_generator(mut self, generator: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        self.config = self.config.with_request_id_generator(generator);
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.config = self.config.with_dry_run(dry_run);
+        self
+    }
+
+    pub fn delete_via_query(mut self, enabled: bool) -> Self {
+        self.config = self.config.with_delete_via_query(enabled);
+        self
+    }
+
+    pub fn max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.config = self.config.with_max_response_bytes(max_bytes);
+        self
+    }
+
+    pub fn max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.config = self.config.with_max_batch_size(max_batch_size);
+        self
+    }
+
+    pub fn fan_out_concurrency(mut self, concurrency: usize) -> Self {
+        self.config = self.config.with_fan_out_concurrency(concurrency);
+        self
+    }
+
+    pub fn identifier_normalization(mut self, enabled: bool) -> Self {
+        self.config = self.config.with_identifier_normalization(enabled);
+        self
+    }
+
+    #[cfg(feature = "replay")]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.config = self.config.with_cassette(cassette);
+        self
+    }
+
+    /// Enable the product details cache with the given number of entries
+    #[cfg(feature = "cache")]
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = Some(capacity);
+        self
+    }
+
+    /// Time-to-live of cached entries; requires `cache_capacity`
+    #[cfg(feature = "cache")]
+    pub fn cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Validate the options and build the configuration
+    ///
+    /// The API key format is checked when the client is created.
+    pub fn build(self) -> Result<Config> {
+        #[allow(unused_mut)]
+        let mut config = self.config;
+
+        #[cfg(feature = "cache")]
+        match (self.cache_capacity, self.cache_ttl) {
+            (Some(capacity), ttl) => {
+                let ttl = ttl.unwrap_or(std::time::Duration::from_secs(300));
+                config = config.with_cache(capacity, ttl);
+            }
+            (None, Some(_)) => {
+                return Err(Error::validation("cache_ttl requires caching to be enabled with cache_capacity"));
+            }
+            (None, None) => {}
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
 }
 
 /// API response metadata containing credit usage info
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ApiMeta {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub credits_used: i32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub credits_remaining: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rate_limit_remaining: Option<i32>,
 }
 
 /// Standard API response wrapper
 #[derive(Debug, Deserialize, Serialize)]
+#[serde(
+    try_from = "ApiResponseRepr<T>",
+    bound(deserialize = "T: serde::de::DeserializeOwned")
+)]
 pub struct ApiResponse<T> {
     pub success: bool,
     pub data: T,
+    /// Status message, or the `error` field when the response only sent that
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<ApiMeta>,
 }
 
@@ -57,18 +710,77 @@ impl<T> ApiResponse<T> {
     }
 }
 
+/// Wire form of [`ApiResponse`], whose `data` may be null or missing
+#[derive(Deserialize)]
+pub(crate) struct ApiResponseRepr<T> {
+    pub(crate) success: bool,
+    data: Option<T>,
+    pub(crate) message: Option<String>,
+    /// Failure reason some endpoints send instead of `message`
+    pub(crate) error: Option<String>,
+    pub(crate) meta: Option<ApiMeta>,
+}
+
+impl<T> TryFrom<ApiResponseRepr<T>> for ApiResponse<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Error = serde::de::value::Error;
+
+    /// Read a null or missing `data` as an empty collection
+    ///
+    /// The API returns `data: null`, or omits it, instead of `[]` when nothing
+    /// matches, so the payload is deserialized from an empty sequence. For list
+    /// payloads this yields an empty `Vec`; other payloads still report an error.
+    fn try_from(repr: ApiResponseRepr<T>) -> std::result::Result<Self, Self::Error> {
+        let data = match repr.data {
+            Some(data) => data,
+            None => T::deserialize(serde::de::value::SeqDeserializer::<_, Self::Error>::new(std::iter::empty::<()>()))?,
+        };
+        Ok(ApiResponse {
+            success: repr.success,
+            data,
+            message: repr.message.or(repr.error),
+            meta: repr.meta,
+        })
+    }
+}
+
+/// Deserialize a missing or null value as the type's default
+///
+/// Numeric counters such as pagination totals are sometimes sent as `null`
+/// (e.g. on empty searches); these are read as 0.
+fn null_as_default<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// Product details information
+///
+/// Equality and hashing are identity-based: two values are equal when they
+/// share the same `shopsavvy` product ID, regardless of the other fields.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ProductDetails {
     pub title: String,
     pub shopsavvy: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub brand: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
-    pub images: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<Image>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amazon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
 }
 
@@ -90,7 +802,127 @@ impl ProductDetails {
 
     /// Get first image URL (deprecated, use images[0])
     pub fn image_url(&self) -> Option<&str> {
-        self.images.as_ref().and_then(|imgs| imgs.first().map(|s| s.as_str()))
+        self.images.as_ref().and_then(|imgs| imgs.first().map(|img| img.url.as_str()))
+    }
+
+    /// Check whether an identifier refers to this product
+    ///
+    /// Compares against the ShopSavvy ID, barcode, ASIN, model and MPN,
+    /// ignoring case. Barcodes match regardless of dashes, spaces or leading
+    /// zeros, so a UPC-A matches its EAN-13 form. Product URLs never match.
+    pub fn matches_identifier(&self, identifier: &str) -> bool {
+        let key = identifier_key(identifier);
+        std::iter::once(Some(self.shopsavvy.as_str()))
+            .chain([
+                self.barcode.as_deref(),
+                self.amazon.as_deref(),
+                self.model.as_deref(),
+                self.mpn.as_deref(),
+            ])
+            .flatten()
+            .any(|candidate| identifier_key(candidate) == key)
+    }
+
+    /// Attach a list of offers to this product
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let product = catalog.remove(&id).unwrap();
+    /// let fresh = client.get_current_offers(&id, None, None, None).await?;
+    /// let entry = product.merge_offers(fresh.data[0].offers.clone());
+    /// ```
+    pub fn merge_offers(self, offers: Vec<Offer>) -> ProductWithOffers {
+        // Destructure exhaustively so new fields cannot be silently skipped
+        let ProductDetails {
+            title,
+            shopsavvy,
+            brand,
+            category,
+            images,
+            barcode,
+            amazon,
+            model,
+            mpn,
+            color,
+        } = self;
+
+        ProductWithOffers {
+            title,
+            shopsavvy,
+            brand,
+            category,
+            images,
+            barcode,
+            amazon,
+            model,
+            mpn,
+            color,
+            offers,
+        }
+    }
+}
+
+impl PartialEq for ProductDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.shopsavvy == other.shopsavvy
+    }
+}
+
+impl Eq for ProductDetails {}
+
+impl std::hash::Hash for ProductDetails {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.shopsavvy.hash(state);
+    }
+}
+
+/// Product image
+///
+/// The API returns images either as plain URL strings or as objects with
+/// dimensions; both forms deserialize into this type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum ImageRepr {
+    Url(String),
+    Object {
+        url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        width: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        height: Option<u32>,
+    },
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(match ImageRepr::deserialize(deserializer)? {
+            ImageRepr::Url(url) => Image { url, width: None, height: None },
+            ImageRepr::Object { url, width, height } => Image { url, width, height },
+        })
+    }
+}
+
+impl Serialize for Image {
+    /// Serializes as a plain URL string when no dimensions are known
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let repr = if self.width.is_none() && self.height.is_none() {
+            ImageRepr::Url(self.url.clone())
+        } else {
+            ImageRepr::Object {
+                url: self.url.clone(),
+                width: self.width,
+                height: self.height,
+            }
+        };
+        repr.serialize(serializer)
     }
 }
 
@@ -106,15 +938,25 @@ pub struct PriceHistoryEntry {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Offer {
     pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retailer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<f64>,
-    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub availability: Option<String>,
-    pub condition: Option<String>,
-    #[serde(rename = "URL")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Condition>,
+    #[serde(rename = "URL", alias = "url", skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub seller: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
+    #[serde(alias = "price_history", skip_serializing_if = "Option::is_none")]
     pub history: Option<Vec<PriceHistoryEntry>>,
 }
 
@@ -124,47 +966,782 @@ impl Offer {
         &self.id
     }
 
-    /// Get offer URL (deprecated, use url)
-    pub fn offer_url(&self) -> Option<&str> {
-        self.url.as_deref()
-    }
+    /// Get offer URL (deprecated, use url)
+    pub fn offer_url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Get last updated time (deprecated, use timestamp)
+    pub fn last_updated(&self) -> Option<&str> {
+        self.timestamp.as_deref()
+    }
+
+    /// Get the embedded price history, or an empty slice when there is none
+    pub fn history_entries(&self) -> &[PriceHistoryEntry] {
+        self.history.as_deref().unwrap_or_default()
+    }
+
+    /// Check whether the offer is priced in US dollars
+    pub fn is_usd(&self) -> bool {
+        self.currency == Some(Currency::Usd)
+    }
+
+    /// Get the total landed cost (price plus shipping)
+    ///
+    /// Returns `None` when the offer has no price or no shipping cost, since the
+    /// landed cost is then unknown. Free shipping is reported as `0.0`.
+    pub fn total_price(&self) -> Option<f64> {
+        Some(self.price? + self.shipping?)
+    }
+
+    /// Check whether the offer's availability indicates it is in stock
+    pub fn is_in_stock(&self) -> bool {
+        self.availability.as_deref().is_some_and(is_in_stock)
+    }
+
+    /// Get the offer URL with tracking query parameters removed
+    ///
+    /// Strips `utm_*` parameters, click IDs and common affiliate tags while
+    /// keeping the product path and any other parameters. Returns `None` when
+    /// the offer has no URL or it cannot be parsed; `url` itself is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // "https://example.com/p/123?color=red&utm_source=feed&gclid=abc"
+    /// assert_eq!(offer.clean_url().as_deref(), Some("https://example.com/p/123?color=red"));
+    /// ```
+    pub fn clean_url(&self) -> Option<String> {
+        let mut url = url::Url::parse(self.url.as_deref()?.trim()).ok()?;
+        strip_tracking_params(&mut url);
+        Some(url.into())
+    }
+
+    /// Time since the offer was last seen, relative to `now`
+    ///
+    /// Returns `None` when the timestamp is missing or unparseable. Timestamps
+    /// without an offset are treated as UTC.
+    #[cfg(feature = "chrono")]
+    pub fn age(&self, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::Duration> {
+        let timestamp = self.timestamp.as_deref()?.trim();
+        let seen = chrono::DateTime::parse_from_rfc3339(timestamp)
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .or_else(|_| {
+                chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S").map(|t| t.and_utc())
+            })
+            .ok()?;
+        Some(now - seen)
+    }
+
+    /// Check whether the offer was last seen more than `max_age` ago
+    ///
+    /// Offers without a usable timestamp are never considered stale.
+    #[cfg(feature = "chrono")]
+    pub fn is_stale(&self, max_age: chrono::Duration) -> bool {
+        self.age(chrono::Utc::now()).is_some_and(|age| age > max_age)
+    }
+}
+
+/// Set `local` from `fresh` when it is not already set
+fn fill_missing<T: Clone>(local: &mut Option<T>, fresh: &Option<T>) {
+    if local.is_none() {
+        local.clone_from(fresh);
+    }
+}
+
+/// Check whether an availability string means the item can be bought now
+pub(crate) fn is_in_stock(availability: &str) -> bool {
+    let normalized = availability.trim().to_ascii_lowercase().replace([' ', '-'], "_");
+    matches!(
+        normalized.as_str(),
+        "in_stock" | "instock" | "available" | "limited_stock" | "low_stock"
+    )
+}
+
+/// Product with nested offers (returned by offers endpoint)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProductWithOffers {
+    pub title: String,
+    pub shopsavvy: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<Image>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amazon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    pub offers: Vec<Offer>,
+}
+
+impl ProductWithOffers {
+    /// Get the product metadata without its offers
+    pub fn to_product_details(&self) -> ProductDetails {
+        let ProductWithOffers {
+            title,
+            shopsavvy,
+            brand,
+            category,
+            images,
+            barcode,
+            amazon,
+            model,
+            mpn,
+            color,
+            offers: _,
+        } = self;
+
+        ProductDetails {
+            title: title.clone(),
+            shopsavvy: shopsavvy.clone(),
+            brand: brand.clone(),
+            category: category.clone(),
+            images: images.clone(),
+            barcode: barcode.clone(),
+            amazon: amazon.clone(),
+            model: model.clone(),
+            mpn: mpn.clone(),
+            color: color.clone(),
+        }
+    }
+
+    /// Refresh this catalog entry from a newer response for the same product
+    ///
+    /// Merge semantics:
+    ///
+    /// * Offers are replaced by `fresh.offers`, so offers no longer listed are
+    ///   dropped. A fresh offer without `history` keeps the history of the
+    ///   existing offer with the same ID.
+    /// * Metadata already set locally is kept; fields missing locally are
+    ///   filled in from `fresh`. An empty local title counts as missing.
+    ///
+    /// Fails without changing anything when `fresh` has a different ShopSavvy ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let fresh = client.get_current_offers(&entry.shopsavvy, None, None, None).await?;
+    /// entry.update_from(&fresh.data[0])?;
+    /// ```
+    pub fn update_from(&mut self, fresh: &ProductWithOffers) -> Result<()> {
+        if self.shopsavvy != fresh.shopsavvy {
+            return Err(Error::validation(format!(
+                "Cannot update product {} from product {}",
+                self.shopsavvy, fresh.shopsavvy
+            )));
+        }
+
+        let ProductWithOffers {
+            title,
+            shopsavvy: _,
+            brand,
+            category,
+            images,
+            barcode,
+            amazon,
+            model,
+            mpn,
+            color,
+            offers,
+        } = fresh;
+
+        if self.title.is_empty() {
+            self.title = title.clone();
+        }
+        fill_missing(&mut self.brand, brand);
+        fill_missing(&mut self.category, category);
+        fill_missing(&mut self.images, images);
+        fill_missing(&mut self.barcode, barcode);
+        fill_missing(&mut self.amazon, amazon);
+        fill_missing(&mut self.model, model);
+        fill_missing(&mut self.mpn, mpn);
+        fill_missing(&mut self.color, color);
+
+        let mut previous: HashMap<String, Offer> = self
+            .offers
+            .drain(..)
+            .map(|offer| (offer.id.clone(), offer))
+            .collect();
+        self.offers = offers
+            .iter()
+            .map(|offer| {
+                let mut offer = offer.clone();
+                if offer.history.is_none() {
+                    offer.history = previous.remove(&offer.id).and_then(|old| old.history);
+                }
+                offer
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Compare against an earlier snapshot of the same product
+    ///
+    /// Offers are matched by normalized retailer name and condition. Offers that
+    /// appear in stock for the first time count as coming in stock, and offers
+    /// that disappeared while in stock count as going out of stock.
+    pub fn changed_since(&self, other: &ProductWithOffers) -> Vec<OfferChange> {
+        let current = offers_by_key(&self.offers);
+        let previous = offers_by_key(&other.offers);
+        let mut changes = Vec::new();
+
+        for (key, offer) in &current {
+            let (retailer, condition) = key;
+            let change = |kind| OfferChange {
+                retailer: retailer.clone(),
+                condition: condition.clone(),
+                kind,
+            };
+
+            match previous.get(key) {
+                Some(before) => {
+                    if let (Some(from), Some(to)) = (before.price, offer.price) {
+                        if to > from {
+                            changes.push(change(OfferChangeKind::PriceUp { from, to }));
+                        } else if to < from {
+                            changes.push(change(OfferChangeKind::PriceDown { from, to }));
+                        }
+                    }
+                    match (before.is_in_stock(), offer.is_in_stock()) {
+                        (false, true) => changes.push(change(OfferChangeKind::CameInStock)),
+                        (true, false) => changes.push(change(OfferChangeKind::WentOutOfStock)),
+                        _ => {}
+                    }
+                }
+                None if offer.is_in_stock() => changes.push(change(OfferChangeKind::CameInStock)),
+                None => {}
+            }
+        }
+
+        for ((retailer, condition), before) in &previous {
+            if before.is_in_stock() && !current.contains_key(&(retailer.clone(), condition.clone())) {
+                changes.push(OfferChange {
+                    retailer: retailer.clone(),
+                    condition: condition.clone(),
+                    kind: OfferChangeKind::WentOutOfStock,
+                });
+            }
+        }
+
+        changes
+    }
+
+    /// Get offers from a retailer, comparing normalized retailer names
+    pub fn offers_from(&self, retailer: &str) -> Vec<&Offer> {
+        let slug = normalize_retailer(retailer);
+        self.offers
+            .iter()
+            .filter(|offer| offer.retailer.as_deref().is_some_and(|r| normalize_retailer(r) == slug))
+            .collect()
+    }
+
+    /// Group offers by normalized retailer name, skipping offers without a retailer
+    pub fn offers_by_retailer(&self) -> HashMap<String, Vec<&Offer>> {
+        let mut grouped: HashMap<String, Vec<&Offer>> = HashMap::new();
+        for offer in &self.offers {
+            if let Some(retailer) = &offer.retailer {
+                grouped.entry(normalize_retailer(retailer)).or_default().push(offer);
+            }
+        }
+        grouped
+    }
+
+    /// Get the cheapest priced offer for each normalized retailer name
+    pub fn cheapest_offer_by_retailer(&self) -> HashMap<String, &Offer> {
+        let mut cheapest: HashMap<String, &Offer> = HashMap::new();
+        for offer in &self.offers {
+            let (Some(retailer), Some(price)) = (&offer.retailer, offer.price) else {
+                continue;
+            };
+            let retailer = normalize_retailer(retailer);
+            let is_cheaper = match cheapest.get(&retailer).and_then(|current| current.price) {
+                Some(current) => price < current,
+                None => true,
+            };
+            if is_cheaper {
+                cheapest.insert(retailer, offer);
+            }
+        }
+        cheapest
+    }
+
+    /// Get the names of retailers with offers, sorted and deduplicated
+    ///
+    /// Sorting and deduplication ignore case; the first spelling seen is kept.
+    pub fn retailers(&self) -> Vec<&str> {
+        let mut retailers: Vec<&str> = self.offers.iter().filter_map(|offer| offer.retailer.as_deref()).collect();
+        retailers.sort_by_cached_key(|retailer| retailer.to_lowercase());
+        retailers.dedup_by_key(|retailer| retailer.to_lowercase());
+        retailers
+    }
+
+    /// Get offers that can be bought now: in stock, with a price and a URL
+    pub fn purchasable_offers(&self) -> Vec<&Offer> {
+        self.offers
+            .iter()
+            .filter(|offer| offer.is_in_stock() && offer.price.is_some() && offer.url.is_some())
+            .collect()
+    }
+
+    /// Compare the cheapest in-stock offer against a reference price such as MSRP
+    ///
+    /// Returns `None` when there is no in-stock priced offer, the reference is
+    /// not positive, or the cheapest offer costs more than the reference.
+    pub fn savings_vs(&self, reference: f64) -> Option<Savings> {
+        if reference <= 0.0 {
+            return None;
+        }
+        let offer = self.cheapest_offer()?;
+        let price = offer.price?;
+        if price > reference {
+            return None;
+        }
+        let amount = reference - price;
+        Some(Savings {
+            amount,
+            pct: amount / reference * 100.0,
+            retailer: offer.retailer.clone(),
+        })
+    }
+
+    /// Get the cheapest in-stock offer with a price
+    pub fn cheapest_offer(&self) -> Option<&Offer> {
+        self.offers
+            .iter()
+            .filter(|offer| offer.is_in_stock())
+            .filter_map(|offer| Some((offer, offer.price?)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(offer, _)| offer)
+    }
+
+    /// Get the in-stock offer with the lowest landed cost (price plus shipping)
+    ///
+    /// With `assume_free_shipping`, offers without a shipping cost count as
+    /// shipping free; otherwise their landed cost is unknown and they are
+    /// skipped. Returns `None` when no offer has a usable price.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if let Some(offer) = product.cheapest_with_shipping(false) {
+    ///     println!("{:?}: {:.2} delivered", offer.retailer, offer.total_price().unwrap());
+    /// }
+    /// ```
+    pub fn cheapest_with_shipping(&self, assume_free_shipping: bool) -> Option<&Offer> {
+        self.offers
+            .iter()
+            .filter(|offer| offer.is_in_stock())
+            .filter_map(|offer| {
+                let shipping = match offer.shipping {
+                    Some(shipping) => shipping,
+                    None if assume_free_shipping => 0.0,
+                    None => return None,
+                };
+                Some((offer, offer.price? + shipping))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(offer, _)| offer)
+    }
+}
+
+/// Index offers by normalized retailer and condition, keeping the first of each
+fn offers_by_key(offers: &[Offer]) -> HashMap<(String, Option<Condition>), &Offer> {
+    let mut indexed = HashMap::new();
+    for offer in offers {
+        if let Some(retailer) = &offer.retailer {
+            indexed
+                .entry((normalize_retailer(retailer), offer.condition.clone()))
+                .or_insert(offer);
+        }
+    }
+    indexed
+}
+
+/// Kind of change detected between two snapshots of an offer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OfferChangeKind {
+    PriceUp { from: f64, to: f64 },
+    PriceDown { from: f64, to: f64 },
+    CameInStock,
+    WentOutOfStock,
+}
+
+/// Change to a retailer's offer between two snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfferChange {
+    /// Normalized retailer name
+    pub retailer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Condition>,
+    pub kind: OfferChangeKind,
+}
+
+/// Price movement of one product between two snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProductPriceChange {
+    pub shopsavvy: String,
+    pub title: String,
+    /// Price changes of the product's offers
+    pub changes: Vec<OfferChange>,
+    /// Largest absolute price change across the offers
+    pub max_change: f64,
+}
+
+/// Find products whose offer prices moved between two snapshots
+///
+/// Products are matched by ShopSavvy ID and their offers are compared with
+/// [`ProductWithOffers::changed_since`]. Only products with at least one price
+/// change are returned, largest movement first.
+pub fn recent_price_changes(previous: &[ProductWithOffers], current: &[ProductWithOffers]) -> Vec<ProductPriceChange> {
+    let mut moved: Vec<ProductPriceChange> = current
+        .iter()
+        .filter_map(|product| {
+            let before = previous.iter().find(|p| p.shopsavvy == product.shopsavvy)?;
+            let changes: Vec<OfferChange> = product
+                .changed_since(before)
+                .into_iter()
+                .filter(|change| matches!(change.kind, OfferChangeKind::PriceUp { .. } | OfferChangeKind::PriceDown { .. }))
+                .collect();
+            let max_change = changes
+                .iter()
+                .map(|change| match change.kind {
+                    OfferChangeKind::PriceUp { from, to } | OfferChangeKind::PriceDown { from, to } => (to - from).abs(),
+                    _ => 0.0,
+                })
+                .fold(0.0, f64::max);
+
+            (!changes.is_empty()).then(|| ProductPriceChange {
+                shopsavvy: product.shopsavvy.clone(),
+                title: product.title.clone(),
+                changes,
+                max_change,
+            })
+        })
+        .collect();
+
+    moved.sort_by(|a, b| b.max_change.total_cmp(&a.max_change));
+    moved
+}
+
+/// Find the cheapest in-stock offer across several products
+///
+/// Compares each product's [`ProductWithOffers::cheapest_offer`]; products
+/// without an in-stock priced offer are skipped.
+pub fn cheapest_across(products: &[ProductWithOffers]) -> Option<(&ProductWithOffers, &Offer)> {
+    products
+        .iter()
+        .filter_map(|product| Some((product, product.cheapest_offer()?)))
+        .min_by(|a, b| a.1.price.unwrap_or(f64::INFINITY).total_cmp(&b.1.price.unwrap_or(f64::INFINITY)))
+}
+
+/// Offer with historical price data
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OfferWithHistory {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retailer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Condition>,
+    #[serde(rename = "URL", alias = "url", skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seller: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    pub price_history: Vec<PriceHistoryEntry>,
+}
+
+impl OfferWithHistory {
+    /// Get the current offer without its price history
+    pub fn current_offer(&self) -> Offer {
+        Offer {
+            id: self.id.clone(),
+            retailer: self.retailer.clone(),
+            price: self.price,
+            shipping: None,
+            currency: self.currency.clone(),
+            availability: self.availability.clone(),
+            condition: self.condition.clone(),
+            url: self.url.clone(),
+            seller: self.seller.clone(),
+            timestamp: self.timestamp.clone(),
+            history: None,
+        }
+    }
+
+    /// Get the most recent history entry by date
+    pub fn latest_history_entry(&self) -> Option<&PriceHistoryEntry> {
+        self.price_history.iter().max_by(|a, b| a.date.cmp(&b.date))
+    }
+
+    /// History entries sorted chronologically
+    fn sorted_history(&self) -> Vec<&PriceHistoryEntry> {
+        let mut entries: Vec<&PriceHistoryEntry> = self.price_history.iter().collect();
+        entries.sort_by(|a, b| a.date.cmp(&b.date));
+        entries
+    }
+
+    /// Get the current price, falling back to the latest history entry
+    fn latest_price(&self) -> Option<f64> {
+        self.price.or_else(|| self.latest_history_entry().map(|entry| entry.price))
+    }
+
+    /// Find the largest drop between consecutive history entries
+    pub fn largest_drop(&self) -> Option<PriceDrop> {
+        self.sorted_history()
+            .windows(2)
+            .filter(|pair| pair[1].price < pair[0].price)
+            .map(|pair| PriceDrop::new(pair[0], pair[1]))
+            .max_by(|a, b| a.amount.total_cmp(&b.amount))
+    }
+
+    /// Compare the latest price with the lowest price in the history
+    pub fn current_vs_lowest(&self) -> Option<LowestPriceComparison> {
+        let current = self.latest_price()?;
+        let lowest = self
+            .price_history
+            .iter()
+            .min_by(|a, b| a.price.total_cmp(&b.price))?;
+        let amount_above = current - lowest.price;
+        Some(LowestPriceComparison {
+            current,
+            lowest: lowest.price,
+            lowest_date: lowest.date.clone(),
+            amount_above,
+            pct_above: if lowest.price > 0.0 { amount_above / lowest.price * 100.0 } else { 0.0 },
+        })
+    }
+
+    /// Get the mean price across the history entries
+    pub fn average_price(&self) -> Option<f64> {
+        average(self.price_history.iter().map(|entry| entry.price))
+    }
+
+    /// Smooth the history prices with an exponential moving average
+    ///
+    /// Returns `(date, smoothed price)` pairs in chronological order, seeded with
+    /// the first price. Higher `alpha` follows recent prices more closely; it
+    /// must be in `(0.0, 1.0]`. Empty history yields an empty vec.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for (date, price) in offer.ema(0.3)? {
+    ///     println!("{}: {:.2}", date, price);
+    /// }
+    /// ```
+    pub fn ema(&self, alpha: f64) -> Result<Vec<(String, f64)>> {
+        if alpha.is_nan() || alpha <= 0.0 || alpha > 1.0 {
+            return Err(Error::validation(format!("EMA alpha must be in (0.0, 1.0], got {}", alpha)));
+        }
+
+        let mut smoothed: Option<f64> = None;
+        Ok(self
+            .sorted_history()
+            .into_iter()
+            .map(|entry| {
+                let value = match smoothed {
+                    Some(previous) => alpha * entry.price + (1.0 - alpha) * previous,
+                    None => entry.price,
+                };
+                smoothed = Some(value);
+                (entry.date.clone(), value)
+            })
+            .collect())
+    }
+
+    /// Get the sample standard deviation of the history prices
+    ///
+    /// Returns `None` with fewer than two history entries.
+    pub fn price_std_dev(&self) -> Option<f64> {
+        let count = self.price_history.len();
+        if count < 2 {
+            return None;
+        }
+        let mean = self.average_price()?;
+        let squared: f64 = self.price_history.iter().map(|entry| (entry.price - mean).powi(2)).sum();
+        Some((squared / (count - 1) as f64).sqrt())
+    }
+
+    /// Get the coefficient of variation (standard deviation / mean) of the history prices
+    ///
+    /// Returns `None` with fewer than two history entries or a zero mean.
+    pub fn price_volatility(&self) -> Option<f64> {
+        let mean = self.average_price().filter(|mean| *mean != 0.0)?;
+        Some(self.price_std_dev()? / mean)
+    }
+
+    /// Get the percentage of history entries priced above the current price
+    ///
+    /// `90.0` means the current price is lower than 90% of the history, so
+    /// higher values are better deals. Returns `None` without a current price
+    /// or with fewer than two history entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if offer.current_price_percentile().is_some_and(|pct| pct >= 90.0) {
+    ///     println!("Great deal");
+    /// }
+    /// ```
+    pub fn current_price_percentile(&self) -> Option<f64> {
+        let count = self.price_history.len();
+        if count < 2 {
+            return None;
+        }
+        let current = self.latest_price()?;
+        let above = self.price_history.iter().filter(|entry| entry.price > current).count();
+        Some(above as f64 / count as f64 * 100.0)
+    }
+}
+
+/// Get the mean price across all history entries of several offers
+pub fn average_history_price(offers: &[OfferWithHistory]) -> Option<f64> {
+    average(offers.iter().flat_map(|offer| offer.price_history.iter().map(|entry| entry.price)))
+}
+
+/// Get the latest known price for each normalized retailer name
+///
+/// Uses each offer's current `price`, falling back to its most recent history
+/// entry. Offers without a retailer or any price are skipped; when a retailer
+/// has several offers the lowest latest price is kept.
+pub fn latest_price_per_retailer(offers: &[OfferWithHistory]) -> HashMap<String, f64> {
+    let mut latest: HashMap<String, f64> = HashMap::new();
+    for offer in offers {
+        let (Some(retailer), Some(price)) = (&offer.retailer, offer.latest_price()) else {
+            continue;
+        };
+        latest
+            .entry(normalize_retailer(retailer))
+            .and_modify(|current| *current = current.min(price))
+            .or_insert(price);
+    }
+    latest
+}
+
+/// Flatten price history into chronological `(date, price)` points per retailer
+///
+/// Offers without a retailer and entries with unparseable dates are skipped.
+/// When a retailer has several prices on the same day the lowest is kept.
+#[cfg(feature = "chrono")]
+pub fn to_time_series(offers: &[OfferWithHistory]) -> HashMap<String, Vec<(chrono::NaiveDate, f64)>> {
+    let mut series: HashMap<String, Vec<(chrono::NaiveDate, f64)>> = HashMap::new();
+    for offer in offers {
+        let Some(retailer) = &offer.retailer else {
+            continue;
+        };
+        let points = series.entry(retailer.clone()).or_default();
+        for entry in &offer.price_history {
+            let day = entry.date.get(..10).unwrap_or(&entry.date);
+            if let Ok(date) = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+                points.push((date, entry.price));
+            }
+        }
+    }
+
+    for points in series.values_mut() {
+        points.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        points.dedup_by_key(|point| point.0);
+    }
+    series.retain(|_, points| !points.is_empty());
+    series
+}
+
+fn average(prices: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = prices.fold((0.0, 0usize), |(sum, count), price| (sum + price, count + 1));
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f64)
+    }
+}
+
+/// Savings of an offer against a reference price
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Savings {
+    /// Reference price minus the offer price
+    pub amount: f64,
+    /// Savings as a percentage of the reference price
+    pub pct: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retailer: Option<String>,
+}
+
+/// Price drop between two history entries
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PriceDrop {
+    pub from_date: String,
+    pub to_date: String,
+    pub from_price: f64,
+    pub to_price: f64,
+    /// Absolute drop in price
+    pub amount: f64,
+    /// Drop as a percentage of `from_price`
+    pub pct: f64,
+}
 
-    /// Get last updated time (deprecated, use timestamp)
-    pub fn last_updated(&self) -> Option<&str> {
-        self.timestamp.as_deref()
+impl PriceDrop {
+    fn new(from: &PriceHistoryEntry, to: &PriceHistoryEntry) -> Self {
+        let amount = from.price - to.price;
+        Self {
+            from_date: from.date.clone(),
+            to_date: to.date.clone(),
+            from_price: from.price,
+            to_price: to.price,
+            amount,
+            pct: if from.price > 0.0 { amount / from.price * 100.0 } else { 0.0 },
+        }
     }
 }
 
-/// Product with nested offers (returned by offers endpoint)
+/// Latest price compared with the historical minimum
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct ProductWithOffers {
-    pub title: String,
-    pub shopsavvy: String,
-    pub brand: Option<String>,
-    pub category: Option<String>,
-    pub images: Option<Vec<String>>,
-    pub barcode: Option<String>,
-    pub amazon: Option<String>,
-    pub model: Option<String>,
-    pub mpn: Option<String>,
-    pub color: Option<String>,
-    pub offers: Vec<Offer>,
+pub struct LowestPriceComparison {
+    pub current: f64,
+    pub lowest: f64,
+    pub lowest_date: String,
+    /// How far the current price is above the lowest
+    pub amount_above: f64,
+    /// `amount_above` as a percentage of the lowest price
+    pub pct_above: f64,
 }
 
-/// Offer with historical price data
+/// Average price comparison between two date ranges
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct OfferWithHistory {
-    pub id: String,
-    pub retailer: Option<String>,
-    pub price: Option<f64>,
-    pub currency: Option<String>,
-    pub availability: Option<String>,
-    pub condition: Option<String>,
-    #[serde(rename = "URL")]
-    pub url: Option<String>,
-    pub seller: Option<String>,
-    pub timestamp: Option<String>,
-    pub price_history: Vec<PriceHistoryEntry>,
+pub struct PriceComparison {
+    /// Average price in the first range, `None` when it has no data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_a: Option<f64>,
+    /// Average price in the second range, `None` when it has no data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_b: Option<f64>,
+    /// Percentage change from `avg_a` to `avg_b`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pct_change: Option<f64>,
+}
+
+impl PriceComparison {
+    pub(crate) fn new(avg_a: Option<f64>, avg_b: Option<f64>) -> Self {
+        let pct_change = match (avg_a, avg_b) {
+            (Some(a), Some(b)) if a != 0.0 => Some((b - a) / a * 100.0),
+            _ => None,
+        };
+        Self { avg_a, avg_b, pct_change }
+    }
 }
 
 /// Scheduled product monitoring information
@@ -173,11 +1750,52 @@ pub struct ScheduledProduct {
     pub product_id: String,
     pub identifier: String,
     pub frequency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retailer: Option<String>,
     pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_refreshed: Option<String>,
 }
 
+/// Changes needed to turn the scheduled products into a desired set
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleDiff {
+    /// Desired identifiers that are not scheduled yet
+    pub to_add: Vec<String>,
+    /// Scheduled identifiers that are no longer desired
+    pub to_remove: Vec<String>,
+}
+
+impl ScheduleDiff {
+    /// Whether the schedule already matches the desired set
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_remove.is_empty()
+    }
+}
+
+/// Compare scheduled products against the identifiers that should be monitored
+///
+/// Products are matched on `identifier`. Feed `to_add` to
+/// `schedule_product_monitoring_batch` and `to_remove` to
+/// `remove_products_from_schedule` to reconcile. Order is preserved and
+/// duplicates are dropped.
+pub fn diff_scheduled(current: &[ScheduledProduct], desired: &[&str]) -> ScheduleDiff {
+    let mut diff = ScheduleDiff::default();
+    for identifier in desired {
+        let scheduled = current.iter().any(|product| product.identifier == *identifier);
+        if !scheduled && !diff.to_add.iter().any(|added| added == identifier) {
+            diff.to_add.push(identifier.to_string());
+        }
+    }
+    for product in current {
+        let wanted = desired.contains(&product.identifier.as_str());
+        if !wanted && !diff.to_remove.contains(&product.identifier) {
+            diff.to_remove.push(product.identifier.clone());
+        }
+    }
+    diff
+}
+
 /// Current billing period details
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct UsagePeriod {
@@ -187,6 +1805,8 @@ pub struct UsagePeriod {
     pub credits_limit: i32,
     pub credits_remaining: i32,
     pub requests_made: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<PlanTier>,
 }
 
 /// API usage and credit information
@@ -194,6 +1814,8 @@ pub struct UsagePeriod {
 pub struct UsageInfo {
     pub current_period: UsagePeriod,
     pub usage_percentage: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<PlanTier>,
 }
 
 impl UsageInfo {
@@ -221,15 +1843,62 @@ impl UsageInfo {
     pub fn billing_period_end(&self) -> &str {
         &self.current_period.end_date
     }
+
+    /// Get the subscription tier, wherever the API reported it
+    pub fn plan_tier(&self) -> Option<&PlanTier> {
+        self.plan.as_ref().or(self.current_period.plan.as_ref())
+    }
+
+    /// Percentage of the period's credits still available
+    pub fn remaining_percentage(&self) -> f64 {
+        (100.0 - self.usage_percentage).clamp(0.0, 100.0)
+    }
+
+    /// Check whether less than `pct` percent of the period's credits remain
+    pub fn is_below_threshold(&self, pct: f64) -> bool {
+        self.remaining_percentage() < pct
+    }
+
+    /// Classify remaining credits against warning and critical thresholds
+    ///
+    /// Both thresholds are percentages of credits remaining, e.g. `10.0` and
+    /// `2.0`. Critical takes precedence when both are crossed.
+    pub fn alert(&self, warning_pct: f64, critical_pct: f64) -> UsageAlert {
+        if self.is_below_threshold(critical_pct) {
+            UsageAlert::Critical
+        } else if self.is_below_threshold(warning_pct) {
+            UsageAlert::Warning
+        } else {
+            UsageAlert::Ok
+        }
+    }
+}
+
+/// Credit level relative to configured warning thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UsageAlert {
+    /// Remaining credits are above the warning threshold
+    Ok,
+    /// Remaining credits fell below the warning threshold
+    Warning,
+    /// Remaining credits fell below the critical threshold
+    Critical,
 }
 
 /// Pagination info for search results
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PaginationInfo {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub total: i32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub limit: i32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub offset: i32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub returned: i32,
+    /// Token for the next page of a cursor-paginated search; `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 /// Product search result with pagination
@@ -237,7 +1906,9 @@ pub struct PaginationInfo {
 pub struct ProductSearchResult {
     pub success: bool,
     pub data: Vec<ProductDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<PaginationInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<ApiMeta>,
 }
 
@@ -251,6 +1922,73 @@ impl ProductSearchResult {
     pub fn credits_remaining(&self) -> i32 {
         self.meta.as_ref().map(|m| m.credits_remaining).unwrap_or(0)
     }
+
+    /// Cursor for the next page, when the search was cursor-paginated
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.pagination.as_ref()?.next_cursor.as_deref()
+    }
+
+    /// Number of products in this page
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check whether this page has no products
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Iterate over the products in this page
+    pub fn iter(&self) -> std::slice::Iter<'_, ProductDetails> {
+        self.data.iter()
+    }
+
+    /// Find the product whose title best matches a query
+    ///
+    /// Titles are scored with case-insensitive Jaro-Winkler similarity in the
+    /// range `0.0..=1.0`. Returns `None` when no title scores at least `threshold`.
+    pub fn best_match(&self, query: &str, threshold: f64) -> Option<&ProductDetails> {
+        let query = query.to_lowercase();
+        self.data
+            .iter()
+            .map(|product| (product, strsim::jaro_winkler(&query, &product.title.to_lowercase())))
+            .filter(|(_, score)| *score >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(product, _)| product)
+    }
+
+    /// Check whether another page of results exists
+    pub fn has_more(&self) -> bool {
+        self.pagination
+            .as_ref()
+            .is_some_and(|p| p.offset + p.returned < p.total)
+    }
+}
+
+impl IntoIterator for ProductSearchResult {
+    type Item = ProductDetails;
+    type IntoIter = std::vec::IntoIter<ProductDetails>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ProductSearchResult {
+    type Item = &'a ProductDetails;
+    type IntoIter = std::slice::Iter<'a, ProductDetails>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl std::ops::Index<usize> for ProductSearchResult {
+    type Output = ProductDetails;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
 }
 
 /// Response from scheduling a product
@@ -265,7 +2003,62 @@ pub struct ScheduleResponse {
 pub struct ScheduleBatchResponse {
     pub identifier: String,
     pub scheduled: bool,
+    #[serde(default)]
     pub product_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ScheduleBatchResponse {
+    /// Whether this identifier was scheduled without an error
+    pub fn is_success(&self) -> bool {
+        self.scheduled && self.error.is_none()
+    }
+}
+
+/// Per-identifier outcomes of a batch schedule
+///
+/// A batch can partially succeed: invalid identifiers are reported with an
+/// `error` while the rest are scheduled as usual. Outcomes keep the order the
+/// server reported them in; `succeeded` and `failed` are views over them.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ScheduleBatchResults {
+    responses: Vec<ScheduleBatchResponse>,
+}
+
+impl ScheduleBatchResults {
+    /// Every outcome, in the order the server reported them
+    pub fn responses(&self) -> &[ScheduleBatchResponse] {
+        &self.responses
+    }
+
+    /// Identifiers that were scheduled
+    pub fn succeeded(&self) -> impl Iterator<Item = &ScheduleBatchResponse> {
+        self.responses.iter().filter(|response| response.is_success())
+    }
+
+    /// Identifiers that could not be scheduled, with the reason in `error`
+    pub fn failed(&self) -> impl Iterator<Item = &ScheduleBatchResponse> {
+        self.responses.iter().filter(|response| !response.is_success())
+    }
+
+    /// Whether every identifier in the batch was scheduled
+    pub fn all_succeeded(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+impl From<Vec<ScheduleBatchResponse>> for ScheduleBatchResults {
+    fn from(responses: Vec<ScheduleBatchResponse>) -> Self {
+        Self { responses }
+    }
+}
+
+impl From<ScheduleBatchResults> for Vec<ScheduleBatchResponse> {
+    fn from(results: ScheduleBatchResults) -> Self {
+        results.responses
+    }
 }
 
 /// Response from removing a product from schedule
@@ -279,6 +2072,72 @@ pub struct RemoveResponse {
 pub struct RemoveBatchResponse {
     pub identifier: String,
     pub removed: bool,
+    /// Whether the identifier was scheduled at all (assumed when not reported)
+    #[serde(default = "default_found")]
+    pub found: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn default_found() -> bool {
+    true
+}
+
+impl RemoveBatchResponse {
+    /// Whether this identifier was removed without an error
+    pub fn is_success(&self) -> bool {
+        self.removed && self.error.is_none()
+    }
+}
+
+/// Per-identifier outcomes of a batch removal
+///
+/// Identifiers that were not scheduled are reported separately from real
+/// failures, so cleanup jobs can tolerate them. Outcomes keep the order the
+/// server reported them in; the accessors below are views over them.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(transparent)]
+pub struct RemoveBatchResults {
+    responses: Vec<RemoveBatchResponse>,
+}
+
+impl RemoveBatchResults {
+    /// Every outcome, in the order the server reported them
+    pub fn responses(&self) -> &[RemoveBatchResponse] {
+        &self.responses
+    }
+
+    /// Identifiers that were removed from the schedule
+    pub fn removed(&self) -> impl Iterator<Item = &RemoveBatchResponse> {
+        self.responses.iter().filter(|response| response.is_success())
+    }
+
+    /// Identifiers that were not scheduled in the first place
+    pub fn not_scheduled(&self) -> impl Iterator<Item = &RemoveBatchResponse> {
+        self.responses.iter().filter(|response| !response.is_success() && !response.found)
+    }
+
+    /// Identifiers that could not be removed, with the reason in `error`
+    pub fn failed(&self) -> impl Iterator<Item = &RemoveBatchResponse> {
+        self.responses.iter().filter(|response| !response.is_success() && response.found)
+    }
+
+    /// Whether no identifier failed; identifiers that were not scheduled are tolerated
+    pub fn all_succeeded(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+impl From<Vec<RemoveBatchResponse>> for RemoveBatchResults {
+    fn from(responses: Vec<RemoveBatchResponse>) -> Self {
+        Self { responses }
+    }
+}
+
+impl From<RemoveBatchResults> for Vec<RemoveBatchResponse> {
+    fn from(results: RemoveBatchResults) -> Self {
+        results.responses
+    }
 }
 
 /// Available output formats
@@ -314,3 +2173,487 @@ impl std::fmt::Display for MonitoringFrequency {
         }
     }
 }
+
+/// Item condition of an offer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Condition {
+    New,
+    Used,
+    Refurbished,
+    OpenBox,
+    /// Condition not known to this SDK version
+    Unknown(String),
+}
+
+impl From<&str> for Condition {
+    fn from(value: &str) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "new" => Condition::New,
+            "used" => Condition::Used,
+            "refurbished" => Condition::Refurbished,
+            "open_box" | "open-box" | "open box" | "openbox" => Condition::OpenBox,
+            _ => Condition::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::New => write!(f, "new"),
+            Condition::Used => write!(f, "used"),
+            Condition::Refurbished => write!(f, "refurbished"),
+            Condition::OpenBox => write!(f, "open_box"),
+            Condition::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Serialize for Condition {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Condition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Condition::from(value.as_str()))
+    }
+}
+
+/// ISO-4217 currency code
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+    Cad,
+    Aud,
+    Jpy,
+    Mxn,
+    /// Any other currency code, stored uppercase
+    Other(String),
+}
+
+impl Currency {
+    /// Get the ISO-4217 code
+    pub fn code(&self) -> &str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Gbp => "GBP",
+            Currency::Cad => "CAD",
+            Currency::Aud => "AUD",
+            Currency::Jpy => "JPY",
+            Currency::Mxn => "MXN",
+            Currency::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for Currency {
+    fn from(value: &str) -> Self {
+        let code = value.trim().to_ascii_uppercase();
+        match code.as_str() {
+            "USD" => Currency::Usd,
+            "EUR" => Currency::Eur,
+            "GBP" => Currency::Gbp,
+            "CAD" => Currency::Cad,
+            "AUD" => Currency::Aud,
+            "JPY" => Currency::Jpy,
+            "MXN" => Currency::Mxn,
+            _ => Currency::Other(code),
+        }
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Currency::from(s))
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Currency::from(value.as_str()))
+    }
+}
+
+/// Subscription plan tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlanTier {
+    Free,
+    Starter,
+    Pro,
+    Enterprise,
+    /// Plan not known to this SDK version
+    Other(String),
+}
+
+impl PlanTier {
+    /// Position in the tier ordering, or `None` for unknown plans
+    fn rank(&self) -> Option<u8> {
+        match self {
+            PlanTier::Free => Some(0),
+            PlanTier::Starter => Some(1),
+            PlanTier::Pro => Some(2),
+            PlanTier::Enterprise => Some(3),
+            PlanTier::Other(_) => None,
+        }
+    }
+
+    /// Whether this plan is the given tier or a higher one
+    ///
+    /// Unknown plans only match themselves.
+    pub fn is_at_least(&self, tier: &PlanTier) -> bool {
+        match (self.rank(), tier.rank()) {
+            (Some(own), Some(required)) => own >= required,
+            _ => self == tier,
+        }
+    }
+}
+
+impl From<&str> for PlanTier {
+    fn from(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "free" => PlanTier::Free,
+            "starter" => PlanTier::Starter,
+            "pro" => PlanTier::Pro,
+            "enterprise" => PlanTier::Enterprise,
+            _ => PlanTier::Other(value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for PlanTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanTier::Free => write!(f, "free"),
+            PlanTier::Starter => write!(f, "starter"),
+            PlanTier::Pro => write!(f, "pro"),
+            PlanTier::Enterprise => write!(f, "enterprise"),
+            PlanTier::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Serialize for PlanTier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlanTier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlanTier::from(value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    /// Product with only the required fields set
+    fn minimal_product(shopsavvy: &str, title: &str) -> ProductDetails {
+        ProductDetails {
+            title: title.to_string(),
+            shopsavvy: shopsavvy.to_string(),
+            brand: None,
+            category: None,
+            images: None,
+            barcode: None,
+            amazon: None,
+            model: None,
+            mpn: None,
+            color: None,
+        }
+    }
+
+    fn hash_of(value: &impl Hash) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn products_with_same_id_and_different_titles_are_equal() {
+        let a = minimal_product("ss_123", "Sony WH-1000XM5");
+        let b = ProductDetails {
+            brand: Some("Sony".to_string()),
+            ..minimal_product("ss_123", "Sony WH-1000XM5 Wireless Headphones")
+        };
+        assert_eq!(a, b);
+        assert_ne!(a, minimal_product("ss_456", "Sony WH-1000XM5"));
+    }
+
+    #[test]
+    fn equal_products_hash_equally() {
+        let a = minimal_product("ss_123", "Sony WH-1000XM5");
+        let b = minimal_product("ss_123", "Sony WH-1000XM5 Wireless Headphones");
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let set: HashSet<ProductDetails> = [a, b, minimal_product("ss_456", "Other")].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn null_data_deserializes_as_empty_list() {
+        let response: ApiResponse<Vec<ProductDetails>> =
+            serde_json::from_str(r#"{"success":true,"data":null}"#).unwrap();
+        assert!(response.success);
+        assert!(response.data.is_empty());
+    }
+
+    #[test]
+    fn missing_data_deserializes_as_empty_list() {
+        let response: ApiResponse<Vec<ProductDetails>> = serde_json::from_str(r#"{"success":true}"#).unwrap();
+        assert!(response.success);
+        assert!(response.data.is_empty());
+    }
+
+    #[test]
+    fn minimal_product_serializes_without_null_keys() {
+        let json = serde_json::to_value(minimal_product("ss_123", "Sony WH-1000XM5")).unwrap();
+        let object = json.as_object().unwrap();
+        assert!(object.values().all(|value| !value.is_null()), "{}", json);
+        assert_eq!(object.len(), 2);
+        assert!(!serde_json::to_string(&json).unwrap().contains("null"));
+    }
+
+    #[test]
+    fn null_pagination_fields_deserialize_as_zero() {
+        let pagination: PaginationInfo =
+            serde_json::from_str(r#"{"total":null,"limit":null,"offset":null,"returned":null}"#).unwrap();
+        assert_eq!(
+            (pagination.total, pagination.limit, pagination.offset, pagination.returned),
+            (0, 0, 0, 0)
+        );
+        assert_eq!(pagination.next_cursor, None);
+    }
+
+    #[test]
+    fn null_meta_credits_deserialize_as_zero() {
+        let meta: ApiMeta =
+            serde_json::from_str(r#"{"credits_used":null,"credits_remaining":null,"rate_limit_remaining":null}"#).unwrap();
+        assert_eq!((meta.credits_used, meta.credits_remaining), (0, 0));
+        assert_eq!(meta.rate_limit_remaining, None);
+    }
+
+    #[test]
+    fn null_pagination_in_search_result() {
+        let result: ProductSearchResult = serde_json::from_str(
+            r#"{"success":true,"data":[],"pagination":{"total":null,"limit":25,"offset":null,"returned":0}}"#,
+        )
+        .unwrap();
+        let pagination = result.pagination.unwrap();
+        assert_eq!((pagination.total, pagination.limit, pagination.offset), (0, 25, 0));
+    }
+
+    #[test]
+    fn condition_deserializes_any_casing() {
+        for (raw, expected) in [
+            ("New", Condition::New),
+            ("NEW", Condition::New),
+            ("new", Condition::New),
+            ("used", Condition::Used),
+            ("Used", Condition::Used),
+            ("Refurbished", Condition::Refurbished),
+            ("REFURBISHED", Condition::Refurbished),
+            ("Open Box", Condition::OpenBox),
+            ("open_box", Condition::OpenBox),
+        ] {
+            let condition: Condition = serde_json::from_value(serde_json::json!(raw)).unwrap();
+            assert_eq!(condition, expected, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn unknown_condition_keeps_raw_value() {
+        let condition: Condition = serde_json::from_str(r#""Like New""#).unwrap();
+        assert_eq!(condition, Condition::Unknown("Like New".to_string()));
+        assert_eq!(serde_json::to_string(&condition).unwrap(), r#""Like New""#);
+    }
+
+    #[test]
+    fn currency_deserializes_any_casing() {
+        for raw in ["USD", "usd", "Usd", " usd "] {
+            let currency: Currency = serde_json::from_value(serde_json::json!(raw)).unwrap();
+            assert_eq!(currency, Currency::Usd, "{:?}", raw);
+        }
+        let currency: Currency = serde_json::from_str(r#""gbp""#).unwrap();
+        assert_eq!(currency, Currency::Gbp);
+    }
+
+    #[test]
+    fn unknown_currency_is_stored_uppercase() {
+        let currency: Currency = serde_json::from_str(r#""chf""#).unwrap();
+        assert_eq!(currency, Currency::Other("CHF".to_string()));
+        assert_eq!(currency.code(), "CHF");
+    }
+
+    #[test]
+    fn to_product_details_keeps_every_field() {
+        let image = Image {
+            url: "https://img.example.com/1.jpg".to_string(),
+            width: Some(640),
+            height: Some(480),
+        };
+        let product = ProductWithOffers {
+            title: "Sony WH-1000XM5".to_string(),
+            shopsavvy: "ss_123".to_string(),
+            brand: Some("Sony".to_string()),
+            category: Some("Headphones".to_string()),
+            images: Some(vec![image.clone()]),
+            barcode: Some("027242923782".to_string()),
+            amazon: Some("B09XS7JWHH".to_string()),
+            model: Some("WH1000XM5/B".to_string()),
+            mpn: Some("WH1000XM5B".to_string()),
+            color: Some("Black".to_string()),
+            offers: Vec::new(),
+        };
+
+        let details = product.to_product_details();
+        assert_eq!(details.title, product.title);
+        assert_eq!(details.shopsavvy, product.shopsavvy);
+        assert_eq!(details.brand, product.brand);
+        assert_eq!(details.category, product.category);
+        assert_eq!(details.images, Some(vec![image]));
+        assert_eq!(details.barcode, product.barcode);
+        assert_eq!(details.amazon, product.amazon);
+        assert_eq!(details.model, product.model);
+        assert_eq!(details.mpn, product.mpn);
+        assert_eq!(details.color, product.color);
+
+        // And back again through merge_offers
+        let round_trip = details.merge_offers(Vec::new());
+        assert_eq!(
+            serde_json::to_value(&round_trip).unwrap(),
+            serde_json::to_value(&product).unwrap()
+        );
+    }
+
+    #[test]
+    fn schedule_batch_results_round_trip_in_server_order() {
+        let json = serde_json::json!([
+            {"identifier": "bad", "scheduled": false, "product_id": "", "error": "Invalid identifier"},
+            {"identifier": "012345678901", "scheduled": true, "product_id": "p1"},
+            {"identifier": "B08N5WRWNW", "scheduled": true, "product_id": "p2"}
+        ]);
+
+        let results: ScheduleBatchResults = serde_json::from_value(json.clone()).unwrap();
+        let succeeded: Vec<&str> = results.succeeded().map(|r| r.identifier.as_str()).collect();
+        let failed: Vec<&str> = results.failed().map(|r| r.identifier.as_str()).collect();
+        assert_eq!(succeeded, ["012345678901", "B08N5WRWNW"]);
+        assert_eq!(failed, ["bad"]);
+        assert!(!results.all_succeeded());
+
+        assert_eq!(serde_json::to_value(&results).unwrap(), json);
+    }
+
+    #[test]
+    fn remove_batch_results_round_trip_in_server_order() {
+        let json = serde_json::json!([
+            {"identifier": "gone", "removed": false, "found": false},
+            {"identifier": "012345678901", "removed": true, "found": true},
+            {"identifier": "locked", "removed": false, "found": true, "error": "Schedule is locked"}
+        ]);
+
+        let results: RemoveBatchResults = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(results.removed().map(|r| r.identifier.as_str()).collect::<Vec<_>>(), ["012345678901"]);
+        assert_eq!(results.not_scheduled().map(|r| r.identifier.as_str()).collect::<Vec<_>>(), ["gone"]);
+        assert_eq!(results.failed().map(|r| r.identifier.as_str()).collect::<Vec<_>>(), ["locked"]);
+        assert!(!results.all_succeeded());
+
+        assert_eq!(serde_json::to_value(&results).unwrap(), json);
+    }
+
+    #[test]
+    fn retailers_fold_case_the_same_way_for_sort_and_dedup() {
+        let offers: Vec<serde_json::Value> = ["Étoile", "amazon", "étoile", "Amazon", "Best Buy"]
+            .iter()
+            .enumerate()
+            .map(|(i, retailer)| serde_json::json!({"id": i.to_string(), "retailer": retailer}))
+            .collect();
+        let product: ProductWithOffers = serde_json::from_value(serde_json::json!({
+            "title": "Widget",
+            "shopsavvy": "p1",
+            "offers": offers
+        }))
+        .unwrap();
+
+        assert_eq!(product.retailers(), ["amazon", "Best Buy", "Étoile"]);
+    }
+
+    #[test]
+    fn config_serializes_durations_as_millis_without_headers() {
+        let config = Config::new("ss_test_abc123")
+            .with_header("Proxy-Authorization", "Basic c2VjcmV0")
+            .with_connect_timeout(std::time::Duration::from_secs(2))
+            .with_retry(RetryConfig::new(2));
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json.get("api_key").is_none());
+        assert!(json.get("headers").is_none(), "{}", json);
+        assert_eq!(json["timeout"], 30_000);
+        assert_eq!(json["connect_timeout"], 2_000);
+        assert_eq!(json["retry"]["base_delay"], 500);
+
+        let loaded: Config = serde_json::from_value(serde_json::json!({
+            "timeout": 5000,
+            "headers": [["X-Team", "pricing"]],
+            "retry": {"max_delay": 1000}
+        }))
+        .unwrap();
+        assert_eq!(loaded.timeout, std::time::Duration::from_secs(5));
+        assert_eq!(loaded.headers, [("X-Team".to_string(), "pricing".to_string())]);
+        assert_eq!(loaded.retry.unwrap().max_delay, std::time::Duration::from_secs(1));
+    }
+
+    /// In-stock product with one offer per `(price, shipping)` pair
+    fn product_with_prices(prices: &[(f64, Option<f64>)]) -> ProductWithOffers {
+        let offers: Vec<serde_json::Value> = prices
+            .iter()
+            .enumerate()
+            .map(|(i, (price, shipping))| {
+                serde_json::json!({"id": i.to_string(), "price": price, "shipping": shipping, "availability": "in_stock"})
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({"title": "Widget", "shopsavvy": "p1", "offers": offers})).unwrap()
+    }
+
+    #[test]
+    fn total_price_is_unknown_without_shipping() {
+        let product = product_with_prices(&[(10.0, Some(2.5)), (10.0, Some(0.0)), (10.0, None)]);
+        let totals: Vec<Option<f64>> = product.offers.iter().map(Offer::total_price).collect();
+        assert_eq!(totals, [Some(12.5), Some(10.0), None]);
+    }
+
+    #[test]
+    fn cheapest_with_shipping_applies_the_unknown_shipping_policy() {
+        let product = product_with_prices(&[(12.0, Some(3.0)), (9.0, None)]);
+        assert_eq!(product.cheapest_with_shipping(false).map(|offer| offer.id.as_str()), Some("0"));
+        assert_eq!(product.cheapest_with_shipping(true).map(|offer| offer.id.as_str()), Some("1"));
+
+        let unknown = product_with_prices(&[(9.0, None)]);
+        assert!(unknown.cheapest_with_shipping(false).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::types::ApiMeta;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use crate::time::{SystemTime, UNIX_EPOCH};
+use std::time::Duration;
+
+/// Maximum number of samples kept by a [`UsageTracker`]
+const MAX_SAMPLES: usize = 1000;
+
+/// Credit balance observed on a single response
+#[derive(Debug, Clone)]
+pub struct UsageSample {
+    pub recorded_at: SystemTime,
+    pub credits_used: i32,
+    pub credits_remaining: i32,
+}
+
+/// Records the credit usage reported by each response
+///
+/// The API only reports the current billing period, so the tracker keeps the
+/// most recent samples locally to reconstruct a consumption curve. Only the
+/// last 1000 samples are kept.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    samples: Mutex<VecDeque<UsageSample>>,
+}
+
+impl UsageTracker {
+    pub(crate) fn record(&self, meta: &ApiMeta) {
+        let mut samples = self.samples.lock().unwrap();
+        if samples.len() == MAX_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(UsageSample {
+            recorded_at: SystemTime::now(),
+            credits_used: meta.credits_used,
+            credits_remaining: meta.credits_remaining,
+        });
+    }
+
+    /// Get all recorded samples, oldest first
+    pub fn samples(&self) -> Vec<UsageSample> {
+        self.samples.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Get the most recent sample
+    pub fn latest(&self) -> Option<UsageSample> {
+        self.samples.lock().unwrap().back().cloned()
+    }
+
+    /// Total credits used across all recorded samples
+    pub fn total_credits_used(&self) -> i64 {
+        self.samples.lock().unwrap().iter().map(|s| i64::from(s.credits_used)).sum()
+    }
+
+    /// Discard all recorded samples
+    pub fn clear(&self) {
+        self.samples.lock().unwrap().clear();
+    }
+}
+
+/// Rate-limit position reported by the most recent response
+#[derive(Debug, Clone)]
+pub struct RateLimitState {
+    /// Requests left in the current window
+    pub remaining: i32,
+    /// Requests allowed per window, if the API reported it
+    pub limit: Option<i32>,
+    /// When the window resets, if the API reported it
+    pub reset_at: Option<SystemTime>,
+    /// When this state was observed
+    pub observed_at: SystemTime,
+}
+
+impl RateLimitState {
+    /// Read the `X-RateLimit-*` response headers, if present
+    ///
+    /// `X-RateLimit-Reset` is accepted both as a Unix timestamp and as a
+    /// number of seconds from now.
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let number = |name: &str| -> Option<i64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+        let now = SystemTime::now();
+        let reset_at = number("x-ratelimit-reset")
+            .and_then(|reset| u64::try_from(reset).ok())
+            .map(|reset| {
+                // Large values are timestamps, small ones are delays
+                if reset >= 1_000_000_000 {
+                    UNIX_EPOCH + Duration::from_secs(reset)
+                } else {
+                    now + Duration::from_secs(reset)
+                }
+            });
+
+        Some(Self {
+            remaining: i32::try_from(number("x-ratelimit-remaining")?).ok()?,
+            limit: number("x-ratelimit-limit").and_then(|limit| i32::try_from(limit).ok()),
+            reset_at,
+            observed_at: now,
+        })
+    }
+}